@@ -240,7 +240,7 @@ pub struct ConcurrentBloomInterval<T: BloomHashIndex> {
     bloom: ConcurrentBloom<T>,
 }
 
-// Directly allow all methods of `AtomicBloom` to be called on `AtomicBloomInterval`.
+// Directly allow all methods of `ConcurrentBloom` to be called on `ConcurrentBloomInterval`.
 impl<T: BloomHashIndex> Deref for ConcurrentBloomInterval<T> {
     type Target = ConcurrentBloom<T>;
     fn deref(&self) -> &Self::Target {
@@ -388,6 +388,25 @@ mod test {
         assert!(false_positive < 2_000, "false_positive: {false_positive}");
     }
 
+    #[test]
+    fn test_concurrent_bloom_interval() {
+        let bloom = ConcurrentBloomInterval::<Hash>::new(100, 0.1, 1000);
+        let key = hash(b"hello");
+        assert!(!bloom.contains(&key));
+        assert!(bloom.add(&key));
+        assert!(!bloom.add(&key));
+        assert!(bloom.contains(&key));
+        // The first interval check only starts the clock.
+        bloom.maybe_reset(0);
+        assert!(bloom.contains(&key));
+        bloom.maybe_reset(u64::MAX);
+        assert!(bloom.contains(&key));
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        bloom.maybe_reset(1);
+        assert!(!bloom.contains(&key));
+        assert!(bloom.add(&key));
+    }
+
     #[test]
     fn test_atomic_bloom_round_trip() {
         let mut rng = rand::thread_rng();
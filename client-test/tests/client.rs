@@ -2,8 +2,15 @@ use {
     futures_util::StreamExt,
     rand::Rng,
     serde_json::{json, Value},
+    solana_client::{
+        connection_cache::ConnectionCache,
+        thin_client::{AccountWatchSource, ThinClient},
+    },
     solana_ledger::{blockstore::Blockstore, get_tmp_ledger_path_auto_delete},
-    solana_pubsub_client::{nonblocking, pubsub_client::PubsubClient},
+    solana_pubsub_client::{
+        nonblocking,
+        pubsub_client::{PubsubClient, PubsubReconnectConfig},
+    },
     solana_rpc::{
         optimistically_confirmed_bank_tracker::OptimisticallyConfirmedBank,
         rpc::{create_test_transaction_entries, populate_blockstore_for_tests},
@@ -14,9 +21,9 @@ use {
     solana_rpc_client_api::{
         config::{
             RpcAccountInfoConfig, RpcBlockSubscribeConfig, RpcBlockSubscribeFilter,
-            RpcProgramAccountsConfig,
+            RpcProgramAccountsConfig, RpcSignatureSubscribeConfig,
         },
-        response::SlotInfo,
+        response::{ReceivedSignatureResult, RpcSignatureResult, SlotInfo},
     },
     solana_runtime::{
         bank::Bank,
@@ -25,26 +32,30 @@ use {
         genesis_utils::{create_genesis_config, GenesisConfigInfo},
     },
     solana_sdk::{
+        client::SyncClient,
         clock::Slot,
         commitment_config::{CommitmentConfig, CommitmentLevel},
+        message::Message,
         native_token::sol_to_lamports,
         pubkey::Pubkey,
-        signature::{Keypair, Signer},
-        system_program, system_transaction,
+        signature::{Keypair, Signature, Signer},
+        system_instruction, system_program, system_transaction,
+        transaction::{Transaction, TransactionError},
+        transport::TransportError,
     },
     solana_streamer::socket::SocketAddrSpace,
-    solana_test_validator::TestValidator,
+    solana_test_validator::{TestValidator, TestValidatorGenesis},
     solana_transaction_status::{
         BlockEncodingOptions, ConfirmedBlock, TransactionDetails, UiTransactionEncoding,
     },
     std::{
         collections::HashSet,
-        net::{IpAddr, SocketAddr},
+        net::{IpAddr, SocketAddr, TcpListener},
         sync::{
             atomic::{AtomicBool, AtomicU64, Ordering},
             Arc, RwLock,
         },
-        thread::sleep,
+        thread::{self, sleep},
         time::{Duration, Instant},
     },
     systemstat::Ipv4Addr,
@@ -120,6 +131,150 @@ fn test_rpc_client() {
     );
 }
 
+#[test]
+fn test_thin_client_send_batch() {
+    solana_logger::setup();
+
+    let alice = Keypair::new();
+    let test_validator = TestValidatorGenesis::default()
+        .tpu_enable_udp(true)
+        .start_with_mint_address(alice.pubkey(), SocketAddrSpace::Unspecified)
+        .expect("validator start failed");
+    let rpc_addr = test_validator
+        .rpc_url()
+        .strip_prefix("http://")
+        .unwrap()
+        .parse()
+        .unwrap();
+    let connection_cache = Arc::new(ConnectionCache::with_udp("test_thin_client_send_batch", 1));
+    let client = ThinClient::new(rpc_addr, *test_validator.tpu(), connection_cache);
+
+    let bob_pubkey = solana_sdk::pubkey::new_rand();
+    let blockhash = client.get_latest_blockhash().unwrap();
+    let transactions: Vec<_> = (0..100)
+        .map(|i| {
+            let lamports = sol_to_lamports(1.0) + i;
+            system_transaction::transfer(&alice, &bob_pubkey, lamports, blockhash)
+        })
+        .collect();
+
+    // Nothing is sent if a transaction is not signed.
+    let mut batch = transactions.clone();
+    batch.push(Transaction::new_unsigned(Message::new(
+        &[system_instruction::transfer(
+            &alice.pubkey(),
+            &bob_pubkey,
+            1,
+        )],
+        Some(&alice.pubkey()),
+    )));
+    assert!(matches!(
+        client.send_batch(&batch),
+        Err(TransportError::TransactionError(
+            TransactionError::SignatureFailure
+        ))
+    ));
+
+    let signatures = client.send_batch(&transactions).unwrap();
+    assert_eq!(
+        signatures,
+        transactions
+            .iter()
+            .map(|tx| tx.signatures[0])
+            .collect::<Vec<_>>()
+    );
+    let statuses = client
+        .poll_for_batch_confirmation(
+            &signatures,
+            CommitmentConfig::processed(),
+            Duration::from_secs(30),
+        )
+        .unwrap();
+    assert!(statuses.iter().all(|status| status == &Some(Ok(()))));
+    assert_eq!(
+        client
+            .get_balance_with_commitment(&bob_pubkey, CommitmentConfig::processed())
+            .unwrap(),
+        sol_to_lamports(100.0) + (0..100).sum::<u64>()
+    );
+}
+
+#[test]
+fn test_thin_client_watch_account() {
+    solana_logger::setup();
+
+    let alice = Keypair::new();
+    let test_validator = TestValidatorGenesis::default()
+        .start_with_mint_address(alice.pubkey(), SocketAddrSpace::Unspecified)
+        .expect("validator start failed");
+    let rpc_addr = test_validator
+        .rpc_url()
+        .strip_prefix("http://")
+        .unwrap()
+        .parse()
+        .unwrap();
+    let connection_cache = Arc::new(ConnectionCache::with_udp(
+        "test_thin_client_watch_account",
+        1,
+    ));
+    let polling_client = ThinClient::new(rpc_addr, *test_validator.tpu(), connection_cache.clone());
+    let mut pubsub_client = ThinClient::new(rpc_addr, *test_validator.tpu(), connection_cache);
+    pubsub_client.set_pubsub_url(&test_validator.rpc_pubsub_url());
+
+    let lamports = sol_to_lamports(1.0);
+    let timeout = Duration::from_secs(60);
+    for (client, expected_source) in [
+        (&polling_client, AccountWatchSource::Polling),
+        (&pubsub_client, AccountWatchSource::Pubsub),
+    ] {
+        let bob_pubkey = solana_sdk::pubkey::new_rand();
+        let mut observed = vec![];
+        let mut sender = None;
+        let now = Instant::now();
+        let source = client
+            .watch_account(
+                &bob_pubkey,
+                CommitmentConfig::processed(),
+                timeout,
+                &mut |account| {
+                    observed.push(account.map(|account| account.lamports));
+                    // Fund bob once the watch has reported the missing account.
+                    if sender.is_none() {
+                        let rpc_client = test_validator.get_rpc_client();
+                        let alice = alice.insecure_clone();
+                        sender = Some(thread::spawn(move || {
+                            let blockhash = rpc_client.get_latest_blockhash().unwrap();
+                            let tx = system_transaction::transfer(
+                                &alice,
+                                &bob_pubkey,
+                                lamports,
+                                blockhash,
+                            );
+                            rpc_client.send_and_confirm_transaction(&tx).unwrap();
+                        }));
+                    }
+                    // Stop at the first balance.
+                    account.is_none()
+                },
+            )
+            .unwrap();
+        sender.unwrap().join().unwrap();
+
+        // The watch returns as soon as the callback stops it.
+        assert!(now.elapsed() < timeout);
+        assert_eq!(source, expected_source);
+        assert_eq!(observed, vec![None, Some(lamports)]);
+        assert_eq!(
+            client.wait_for_balance_with_commitment(
+                &bob_pubkey,
+                Some(lamports),
+                CommitmentConfig::processed()
+            ),
+            Some(lamports)
+        );
+    }
+}
+
 #[test]
 fn test_account_subscription() {
     let pubsub_addr = pubsub_addr();
@@ -532,6 +687,219 @@ fn test_slot_subscription() {
     assert_eq!(errors, [].to_vec());
 }
 
+#[test]
+fn test_slot_subscription_with_reconnect() {
+    let pubsub_addr = pubsub_addr();
+    let exit = Arc::new(AtomicBool::new(false));
+    let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(10_000);
+    let bank = Bank::new_for_tests(&genesis_config);
+    let bank_forks = BankForks::new_rw_arc(bank);
+    let optimistically_confirmed_bank =
+        OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks);
+    let max_complete_transaction_status_slot = Arc::new(AtomicU64::default());
+    let max_complete_rewards_slot = Arc::new(AtomicU64::default());
+    let subscriptions = Arc::new(RpcSubscriptions::new_for_tests(
+        exit.clone(),
+        max_complete_transaction_status_slot,
+        max_complete_rewards_slot,
+        bank_forks,
+        Arc::new(RwLock::new(BlockCommitmentCache::default())),
+        optimistically_confirmed_bank,
+    ));
+    let (trigger, pubsub_service) =
+        PubSubService::new(PubSubConfig::default(), &subscriptions, pubsub_addr);
+
+    check_server_is_ready_or_panic(&pubsub_addr, 10, Duration::from_millis(300));
+
+    let (mut client, receiver) = PubsubClient::slot_subscribe_with_reconnect(
+        &format!("ws://0.0.0.0:{}/", pubsub_addr.port()),
+        PubsubReconnectConfig {
+            max_retries: 50,
+            retry_delay: Duration::from_millis(100),
+        },
+    )
+    .unwrap();
+
+    subscriptions.notify_slot(1, 0, 0);
+    let expected = SlotInfo {
+        slot: 1,
+        parent: 0,
+        root: 0,
+    };
+    assert_eq!(
+        receiver.recv_timeout(Duration::from_millis(400)).unwrap(),
+        expected
+    );
+
+    // Restart the server; the subscription should resubscribe on its own.
+    trigger.cancel();
+    pubsub_service.close().unwrap();
+    let (trigger, pubsub_service) =
+        PubSubService::new(PubSubConfig::default(), &subscriptions, pubsub_addr);
+    check_server_is_ready_or_panic(&pubsub_addr, 10, Duration::from_millis(300));
+
+    let expected = SlotInfo {
+        slot: 2,
+        parent: 1,
+        root: 1,
+    };
+    let mut received = None;
+    for _ in 0..50 {
+        subscriptions.notify_slot(2, 1, 1);
+        if let Ok(actual) = receiver.recv_timeout(Duration::from_millis(200)) {
+            received = Some(actual);
+            break;
+        }
+    }
+
+    exit.store(true, Ordering::Relaxed);
+    trigger.cancel();
+    client.shutdown().unwrap();
+    pubsub_service.close().unwrap();
+
+    assert_eq!(received, Some(expected));
+}
+
+#[test]
+fn test_slot_subscription_with_reconnect_after_close() {
+    // A server that closes the connection gracefully, as on shutdown, and
+    // then accepts the resubscription.
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let pubsub_addr = listener.local_addr().unwrap();
+    let server = thread::spawn(move || {
+        for subscription_id in [1, 2] {
+            let (stream, _) = listener.accept().unwrap();
+            let mut socket = tungstenite::accept(stream).unwrap();
+            let request: Value =
+                serde_json::from_str(&socket.read().unwrap().into_text().unwrap()).unwrap();
+            assert_eq!(request["method"], "slotSubscribe");
+            socket
+                .send(tungstenite::Message::Text(
+                    json!({"jsonrpc": "2.0", "result": subscription_id, "id": request["id"]})
+                        .to_string(),
+                ))
+                .unwrap();
+            if subscription_id == 2 {
+                socket
+                    .send(tungstenite::Message::Text(
+                        json!({
+                            "jsonrpc": "2.0",
+                            "method": "slotNotification",
+                            "params": {
+                                "result": {"slot": 2, "parent": 1, "root": 1},
+                                "subscription": subscription_id,
+                            },
+                        })
+                        .to_string(),
+                    ))
+                    .unwrap();
+            }
+            // The client replies to the close frame only on its next read,
+            // which it does on a new connection.
+            socket.close(None).unwrap();
+        }
+    });
+
+    let (mut client, receiver) = PubsubClient::slot_subscribe_with_reconnect(
+        &format!("ws://{pubsub_addr}/"),
+        PubsubReconnectConfig {
+            max_retries: 3,
+            retry_delay: Duration::from_millis(10),
+        },
+    )
+    .unwrap();
+
+    let expected = SlotInfo {
+        slot: 2,
+        parent: 1,
+        root: 1,
+    };
+    assert_eq!(
+        receiver.recv_timeout(Duration::from_secs(5)).unwrap(),
+        expected
+    );
+    server.join().unwrap();
+    client.shutdown().unwrap();
+}
+
+#[test]
+fn test_signature_subscription_with_reconnect_after_received() {
+    let pubsub_addr = pubsub_addr();
+    let exit = Arc::new(AtomicBool::new(false));
+    let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(10_000);
+    let bank = Bank::new_for_tests(&genesis_config);
+    let bank_forks = BankForks::new_rw_arc(bank);
+    let optimistically_confirmed_bank =
+        OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks);
+    let max_complete_transaction_status_slot = Arc::new(AtomicU64::default());
+    let max_complete_rewards_slot = Arc::new(AtomicU64::default());
+    let subscriptions = Arc::new(RpcSubscriptions::new_for_tests(
+        exit.clone(),
+        max_complete_transaction_status_slot,
+        max_complete_rewards_slot,
+        bank_forks,
+        Arc::new(RwLock::new(BlockCommitmentCache::default())),
+        optimistically_confirmed_bank,
+    ));
+    let (trigger, pubsub_service) =
+        PubSubService::new(PubSubConfig::default(), &subscriptions, pubsub_addr);
+
+    check_server_is_ready_or_panic(&pubsub_addr, 10, Duration::from_millis(300));
+
+    let signature = Signature::new_unique();
+    let (mut client, receiver) = PubsubClient::signature_subscribe_with_reconnect(
+        &format!("ws://0.0.0.0:{}/", pubsub_addr.port()),
+        &signature,
+        Some(RpcSignatureSubscribeConfig {
+            commitment: Some(CommitmentConfig::processed()),
+            enable_received_notification: Some(true),
+        }),
+        PubsubReconnectConfig {
+            max_retries: 50,
+            retry_delay: Duration::from_millis(100),
+        },
+    )
+    .unwrap();
+
+    let expected =
+        RpcSignatureResult::ReceivedSignature(ReceivedSignatureResult::ReceivedSignature);
+    let mut received = None;
+    for _ in 0..50 {
+        subscriptions.notify_signatures_received((0, vec![signature]));
+        if let Ok(actual) = receiver.recv_timeout(Duration::from_millis(200)) {
+            received = Some(actual.value);
+            break;
+        }
+    }
+    assert_eq!(received, Some(expected.clone()));
+
+    // The "received" notification is not final, so the subscription must be
+    // renewed when the server restarts.
+    trigger.cancel();
+    pubsub_service.close().unwrap();
+    // Drop notifications for repeated sends from before the restart.
+    while receiver.try_recv().is_ok() {}
+    let (trigger, pubsub_service) =
+        PubSubService::new(PubSubConfig::default(), &subscriptions, pubsub_addr);
+    check_server_is_ready_or_panic(&pubsub_addr, 10, Duration::from_millis(300));
+
+    let mut received = None;
+    for _ in 0..50 {
+        subscriptions.notify_signatures_received((0, vec![signature]));
+        if let Ok(actual) = receiver.recv_timeout(Duration::from_millis(200)) {
+            received = Some(actual.value);
+            break;
+        }
+    }
+
+    exit.store(true, Ordering::Relaxed);
+    trigger.cancel();
+    client.shutdown().unwrap();
+    pubsub_service.close().unwrap();
+
+    assert_eq!(received, Some(expected));
+}
+
 #[tokio::test]
 async fn test_slot_subscription_async() {
     let sync_service = Arc::new(AtomicU64::new(0));
@@ -2,6 +2,7 @@
 //! a server-side TPU.  Client code should use this object instead of writing
 //! messages to the network directly. The binary encoding of its messages are
 //! unstable and may change in future releases.
+pub use solana_thin_client::thin_client::AccountWatchSource;
 #[allow(deprecated)]
 use {
     crate::connection_cache::{dispatch, ConnectionCache},
@@ -117,6 +118,8 @@ impl ThinClient {
 
     dispatch!(pub fn rpc_client(&self) -> &RpcClient);
 
+    dispatch!(pub fn set_pubsub_url(&mut self, pubsub_url: &str));
+
     dispatch!(pub fn retry_transfer_until_confirmed(&self, keypair: &Keypair, transaction: &mut Transaction, tries: usize, min_confirmed_blocks: usize) -> TransportResult<Signature>);
 
     dispatch!(pub fn retry_transfer(
@@ -134,6 +137,15 @@ impl ThinClient {
         pending_confirmations: usize
     ) -> TransportResult<Signature>);
 
+    dispatch!(pub fn send_batch(&self, transactions: &[Transaction]) -> TransportResult<Vec<Signature>>);
+
+    dispatch!(pub fn poll_for_batch_confirmation(
+        &self,
+        signatures: &[Signature],
+        commitment_config: CommitmentConfig,
+        timeout: Duration
+    ) -> TransportResult<Vec<Option<transaction::Result<()>>>>);
+
     dispatch!(pub fn poll_get_balance(&self, pubkey: &Pubkey) -> TransportResult<u64>);
 
     dispatch!(pub fn poll_get_balance_with_commitment(
@@ -157,6 +169,14 @@ impl ThinClient {
         commitment_config: CommitmentConfig
     ) -> Option<u64>);
 
+    dispatch!(pub fn watch_account(
+        &self,
+        pubkey: &Pubkey,
+        commitment_config: CommitmentConfig,
+        timeout: Duration,
+        on_change: &mut dyn FnMut(Option<&Account>) -> bool
+    ) -> TransportResult<AccountWatchSource>);
+
     dispatch!(pub fn poll_for_signature_with_commitment(
         &self,
         signature: &Signature,
@@ -5,7 +5,11 @@ use {
     },
     solana_gossip::cluster_info::ClusterInfo,
     solana_runtime::bank_forks::BankForks,
-    solana_sdk::{pubkey::Pubkey, quic::NotifyKeyUpdate},
+    solana_sdk::{
+        pubkey::Pubkey,
+        quic::NotifyKeyUpdate,
+        signature::{Keypair, Signer},
+    },
     std::{
         collections::HashSet,
         net::UdpSocket,
@@ -24,3 +28,20 @@ pub struct AdminRpcRequestMetadataPostInit {
     pub outstanding_repair_requests: Arc<RwLock<OutstandingRequests<ShredRepairType>>>,
     pub cluster_slots: Arc<ClusterSlots>,
 }
+
+impl AdminRpcRequestMetadataPostInit {
+    /// Switches the node to `identity_keypair`. The network layer and gossip
+    /// pick up the new keypair right away, and replay reloads the tower saved
+    /// for the new identity before it votes again.
+    pub fn set_identity(&self, identity_keypair: Arc<Keypair>) {
+        for n in self.notifies.iter() {
+            if let Err(err) = n.update_key(&identity_keypair) {
+                error!("Error updating network layer keypair: {err}");
+            }
+        }
+
+        solana_metrics::set_host_id(identity_keypair.pubkey().to_string());
+        self.cluster_info.set_keypair(identity_keypair);
+        warn!("Identity set to {}", self.cluster_info.id());
+    }
+}
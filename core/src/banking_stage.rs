@@ -92,6 +92,7 @@ pub struct BankingStageStats {
     receive_and_buffer_packets_count: AtomicUsize,
     dropped_packets_count: AtomicUsize,
     pub(crate) dropped_duplicated_packets_count: AtomicUsize,
+    pub(crate) dropped_already_processed_packets_count: AtomicUsize,
     dropped_forward_packets_count: AtomicUsize,
     newly_buffered_packets_count: AtomicUsize,
     newly_buffered_forwarded_packets_count: AtomicUsize,
@@ -130,6 +131,9 @@ impl BankingStageStats {
             + self
                 .dropped_duplicated_packets_count
                 .load(Ordering::Relaxed) as u64
+            + self
+                .dropped_already_processed_packets_count
+                .load(Ordering::Relaxed) as u64
             + self.dropped_forward_packets_count.load(Ordering::Relaxed) as u64
             + self.newly_buffered_packets_count.load(Ordering::Relaxed) as u64
             + self.current_buffered_packets_count.load(Ordering::Relaxed) as u64
@@ -175,6 +179,12 @@ impl BankingStageStats {
                         .swap(0, Ordering::Relaxed),
                     i64
                 ),
+                (
+                    "dropped_already_processed_packets_count",
+                    self.dropped_already_processed_packets_count
+                        .swap(0, Ordering::Relaxed),
+                    i64
+                ),
                 (
                     "dropped_forward_packets_count",
                     self.dropped_forward_packets_count
@@ -796,7 +806,10 @@ mod tests {
             get_tmp_ledger_path_auto_delete,
             leader_schedule_cache::LeaderScheduleCache,
         },
-        solana_perf::packet::{to_packet_batches, PacketBatch},
+        solana_perf::{
+            packet::{to_packet_batches, PacketBatch},
+            test_tx::{test_keypairs, test_seed},
+        },
         solana_poh::{
             poh_recorder::{
                 create_test_recorder, PohRecorderError, Record, RecordTransactionsSummary,
@@ -1368,8 +1381,8 @@ mod tests {
                 &Arc::new(PrioritizationFeeCache::new(0u64)),
             );
 
-            let keypairs = (0..100).map(|_| Keypair::new()).collect_vec();
-            let vote_keypairs = (0..100).map(|_| Keypair::new()).collect_vec();
+            let mut keypairs = test_keypairs(test_seed(), 200);
+            let vote_keypairs = keypairs.split_off(100);
             for keypair in keypairs.iter() {
                 bank.process_transaction(&system_transaction::transfer(
                     &mint_keypair,
@@ -218,6 +218,44 @@ fn consume_scan_should_process_packet(
     }
 }
 
+/// Drops packets that already landed on this fork, e.g. ones forwarded by a
+/// previous leader, before the scanner sanitizes them and takes their locks.
+/// The status cache is checked once per chunk of packets instead of per packet.
+fn filter_already_processed_packets(
+    bank: &Bank,
+    banking_stage_stats: &BankingStageStats,
+    packets: Vec<Arc<ImmutableDeserializedPacket>>,
+    message_hash_to_transaction: &mut HashMap<Hash, DeserializedPacket>,
+) -> Vec<Arc<ImmutableDeserializedPacket>> {
+    let mut retained_packets = Vec::with_capacity(packets.len());
+    for chunk in packets.chunks(UNPROCESSED_BUFFER_STEP_SIZE) {
+        let already_processed = bank.check_already_processed(chunk.iter().map(|packet| {
+            (
+                packet.message_hash(),
+                packet
+                    .transaction()
+                    .get_message()
+                    .message
+                    .recent_blockhash(),
+            )
+        }));
+        for (packet, already_processed) in chunk.iter().zip(already_processed) {
+            if already_processed {
+                message_hash_to_transaction.remove(packet.message_hash());
+            } else {
+                retained_packets.push(packet.clone());
+            }
+        }
+    }
+    banking_stage_stats
+        .dropped_already_processed_packets_count
+        .fetch_add(
+            packets.len().saturating_sub(retained_packets.len()),
+            Ordering::Relaxed,
+        );
+    retained_packets
+}
+
 fn create_consume_multi_iterator<'a, 'b, F>(
     packets: &'a [Arc<ImmutableDeserializedPacket>],
     slot_metrics_tracker: &'b mut LeaderSlotMetricsTracker,
@@ -502,6 +540,12 @@ impl VoteStorage {
 
         // vote storage does not have a message hash map, so pass in an empty one
         let mut dummy_message_hash_to_transaction = HashMap::new();
+        let all_vote_packets = filter_already_processed_packets(
+            &bank,
+            banking_stage_stats,
+            all_vote_packets,
+            &mut dummy_message_hash_to_transaction,
+        );
         let mut scanner = create_consume_multi_iterator(
             &all_vote_packets,
             slot_metrics_tracker,
@@ -906,7 +950,12 @@ impl ThreadLocalUnprocessedPackets {
         let mut retryable_packets = self.take_priority_queue();
         let original_capacity = retryable_packets.capacity();
         let mut new_retryable_packets = MinMaxHeap::with_capacity(original_capacity);
-        let all_packets_to_process = retryable_packets.drain_desc().collect_vec();
+        let all_packets_to_process = filter_already_processed_packets(
+            bank,
+            banking_stage_stats,
+            retryable_packets.drain_desc().collect_vec(),
+            &mut self.unprocessed_packet_batches.message_hash_to_transaction,
+        );
 
         let should_process_packet =
             |packet: &Arc<ImmutableDeserializedPacket>, payload: &mut ConsumeScannerPayload| {
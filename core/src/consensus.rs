@@ -1504,6 +1504,9 @@ pub enum TowerError {
 
     #[error("The tower is useless because of new hard fork: {0}")]
     HardFork(Slot),
+
+    #[error("Lost the tower lease for {0}")]
+    LostLease(Pubkey),
 }
 
 // Tower1_14_11 is the persisted data format for the Tower, decoupling it from VoteState::Current
@@ -1536,6 +1539,9 @@ impl TowerError {
     pub fn is_too_old(&self) -> bool {
         matches!(self, TowerError::TooOldTower(_, _))
     }
+    pub fn is_lost_lease(&self) -> bool {
+        matches!(self, TowerError::LostLease(_))
+    }
 }
 
 #[derive(Debug)]
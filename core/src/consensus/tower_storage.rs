@@ -8,9 +8,11 @@ use {
         signature::{Signature, Signer},
     },
     std::{
-        fs::{self, File},
+        collections::HashMap,
+        fs::{self, File, OpenOptions},
         io::{self, BufReader},
-        path::PathBuf,
+        path::{Path, PathBuf},
+        sync::RwLock,
     },
 };
 
@@ -110,6 +112,41 @@ impl SavedTower {
 pub trait TowerStorage: Sync + Send {
     fn load(&self, node_pubkey: &Pubkey) -> Result<Tower>;
     fn store(&self, saved_tower: &SavedTowerVersions) -> Result<()>;
+
+    /// Loads `node_pubkey`'s tower like `load`, but without taking the lease
+    /// on it, so that the instance holding the lease keeps storing it.
+    fn peek(&self, node_pubkey: &Pubkey) -> Result<Tower> {
+        self.load(node_pubkey)
+    }
+
+    /// Returns true if the storage is shared between instances and a
+    /// successful `load` fences off every other instance, so that their
+    /// `store` of the same identity's tower fails from then on. Loading a
+    /// tower whose lease this instance still holds keeps that lease.
+    fn is_fenced(&self) -> bool {
+        false
+    }
+
+    /// Returns true if `node_pubkey`'s tower is stored under a lease, so that
+    /// taking the lease fences off the instance storing it. Always false if
+    /// not fenced.
+    fn is_leased(&self, _node_pubkey: &Pubkey) -> Result<bool> {
+        Ok(false)
+    }
+
+    /// Fails with [`TowerError::LostLease`] if this instance no longer holds
+    /// the lease on `node_pubkey`'s tower that it took by loading it; any
+    /// other error means the lease could not be checked. Always succeeds if
+    /// not fenced.
+    fn check_lease(&self, _node_pubkey: &Pubkey) -> Result<()> {
+        Ok(())
+    }
+
+    /// Gives the lease on `node_pubkey`'s tower back to the instance that held
+    /// it before this one loaded the tower, if that is still possible.
+    fn release(&self, _node_pubkey: &Pubkey) -> Result<()> {
+        Ok(())
+    }
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -151,6 +188,29 @@ impl FileTowerStorage {
             .with_extension("bin")
     }
 
+    fn load_from(filename: &Path, node_pubkey: &Pubkey) -> Result<Tower> {
+        trace!("load {}", filename.display());
+        let mut stream = BufReader::new(File::open(filename)?);
+        bincode::deserialize_from(&mut stream)
+            .map_err(|e| e.into())
+            .and_then(|t: SavedTowerVersions| t.try_into_tower(node_pubkey))
+    }
+
+    fn store_to(filename: &Path, saved_tower: &SavedTowerVersions) -> Result<()> {
+        trace!("store: {}", filename.display());
+        let new_filename = filename.with_extension("bin.new");
+
+        {
+            // overwrite anything if exists
+            let mut file = File::create(&new_filename)?;
+            saved_tower.serialize_into(&mut file)?;
+            // file.sync_all() hurts performance; pipeline sync-ing and submitting votes to the cluster!
+        }
+        fs::rename(&new_filename, filename)?;
+        // self.path.parent().sync_all() hurts performance same as the above sync
+        Ok(())
+    }
+
     #[cfg(test)]
     fn store_old(&self, saved_tower: &SavedTower1_7_14) -> Result<()> {
         let pubkey = saved_tower.node_pubkey;
@@ -198,26 +258,247 @@ impl TowerStorage for FileTowerStorage {
     }
 
     fn store(&self, saved_tower: &SavedTowerVersions) -> Result<()> {
-        let pubkey = saved_tower.pubkey();
-        let filename = self.filename(&pubkey);
-        trace!("store: {}", filename.display());
-        let new_filename = filename.with_extension("bin.new");
+        Self::store_to(&self.filename(&saved_tower.pubkey()), saved_tower)
+    }
+}
 
-        {
-            // overwrite anything if exists
-            let mut file = File::create(&new_filename)?;
-            saved_tower.serialize_into(&mut file)?;
-            // file.sync_all() hurts performance; pipeline sync-ing and submitting votes to the cluster!
+/// [`FileTowerStorage`] over a directory shared by several instances of the
+/// same validator, such as a primary and its standby, possibly over NFS.
+///
+/// Like [`EtcdTowerStorage`], loading a tower takes over the identity's lease
+/// and storing requires holding it. Taking over claims the next lease epoch
+/// by exclusively creating its lease file, and the holder of an epoch stores
+/// its tower in a file of its own, so a late store from a previous holder
+/// never overwrites the current holder's tower. A store checks that no later
+/// epoch was claimed only once the tower is in place: a takeover that claims
+/// an epoch after the check reads that tower, and one that claims it before
+/// makes the store fail, so the vote is never sent.
+///
+/// Once a holder has stored its tower, it prunes the files of earlier epochs,
+/// including the tower stored before any lease was taken. A tower stored
+/// without a lease, by a [`FileTowerStorage`] over the same directory, is
+/// therefore left in place and shows that the identity is not fenced.
+///
+/// This relies on exclusive file creation being atomic, not on file locks.
+/// Over NFS, that takes NFSv3 or later, and the clients must be mounted with
+/// `lookupcache=none,noac` so that a claim is seen as soon as it is made.
+pub struct SharedFileTowerStorage {
+    file_tower_storage: FileTowerStorage,
+    // Lease held for each identity this instance has loaded the tower of.
+    leases: RwLock<HashMap<Pubkey, Lease>>,
+}
+
+#[derive(Clone, Copy, Debug)]
+struct Lease {
+    epoch: u64,
+    // Whether the files of earlier epochs were pruned.
+    pruned: bool,
+}
+
+impl SharedFileTowerStorage {
+    pub fn new(tower_path: PathBuf) -> Self {
+        Self {
+            file_tower_storage: FileTowerStorage::new(tower_path),
+            leases: RwLock::default(),
+        }
+    }
+
+    /// Tower file stored by the holder of lease `epoch`. Epoch 0 is the tower
+    /// of a [`FileTowerStorage`] in the same directory, stored before any
+    /// lease was taken.
+    pub fn filename(&self, node_pubkey: &Pubkey, epoch: u64) -> PathBuf {
+        if epoch == 0 {
+            return self.file_tower_storage.filename(node_pubkey);
+        }
+        self.file_tower_storage
+            .tower_path
+            .join(format!("tower-1_9-{node_pubkey}-{epoch}"))
+            .with_extension("bin")
+    }
+
+    pub fn lease_filename(&self, node_pubkey: &Pubkey, epoch: u64) -> PathBuf {
+        self.file_tower_storage
+            .tower_path
+            .join(format!("tower-lease-{node_pubkey}-{epoch}"))
+    }
+
+    // Marks lease `epoch` as given back to the holder of the one before.
+    fn released_filename(&self, node_pubkey: &Pubkey, epoch: u64) -> PathBuf {
+        self.lease_filename(node_pubkey, epoch)
+            .with_extension("released")
+    }
+
+    // Returns the latest claimed lease epoch, or 0 if none was claimed.
+    fn latest_epoch(&self, node_pubkey: &Pubkey) -> Result<u64> {
+        let prefix = format!("tower-lease-{node_pubkey}-");
+        let mut latest_epoch = 0;
+        for entry in fs::read_dir(&self.file_tower_storage.tower_path)? {
+            let epoch = entry?
+                .file_name()
+                .to_str()
+                .and_then(|name| name.strip_prefix(&prefix))
+                .and_then(|epoch| epoch.parse().ok());
+            latest_epoch = latest_epoch.max(epoch.unwrap_or_default());
+        }
+        Ok(latest_epoch)
+    }
+
+    fn claim_next_epoch(&self, node_pubkey: &Pubkey) -> Result<u64> {
+        fs::create_dir_all(&self.file_tower_storage.tower_path)?;
+        loop {
+            let epoch = self.latest_epoch(node_pubkey)? + 1;
+            match OpenOptions::new()
+                .write(true)
+                .create_new(true)
+                .open(self.lease_filename(node_pubkey, epoch))
+            {
+                Ok(lease) => {
+                    lease.sync_all()?;
+                    return Ok(epoch);
+                }
+                // Claimed by another instance since the epochs were listed
+                Err(err) if err.kind() == io::ErrorKind::AlreadyExists => continue,
+                Err(err) => return Err(err.into()),
+            }
+        }
+    }
+
+    // Loads the tower of the latest holder before `epoch` that stored one.
+    fn load_before(&self, node_pubkey: &Pubkey, epoch: u64) -> Result<Tower> {
+        for previous_epoch in (1..epoch).rev() {
+            match FileTowerStorage::load_from(
+                &self.filename(node_pubkey, previous_epoch),
+                node_pubkey,
+            ) {
+                Err(err) if err.is_file_missing() => continue,
+                result => return result,
+            }
+        }
+        self.file_tower_storage.load(node_pubkey)
+    }
+
+    fn lease(&self, node_pubkey: &Pubkey) -> Result<Lease> {
+        self.leases
+            .read()
+            .unwrap()
+            .get(node_pubkey)
+            .copied()
+            .ok_or(TowerError::LostLease(*node_pubkey))
+    }
+
+    // Removes the files of the epochs before `epoch`, which no load reads
+    // once a tower was stored under it.
+    fn prune(&self, node_pubkey: &Pubkey, epoch: u64) {
+        for old_epoch in 0..epoch {
+            for filename in [
+                self.filename(node_pubkey, old_epoch),
+                self.lease_filename(node_pubkey, old_epoch),
+                self.released_filename(node_pubkey, old_epoch),
+            ] {
+                match fs::remove_file(&filename) {
+                    Err(err) if err.kind() != io::ErrorKind::NotFound => {
+                        warn!("Unable to prune {}: {err}", filename.display());
+                    }
+                    _ => (),
+                }
+            }
+        }
+    }
+}
+
+impl TowerStorage for SharedFileTowerStorage {
+    fn load(&self, node_pubkey: &Pubkey) -> Result<Tower> {
+        // Keep a lease this instance still holds, such as the one the standby
+        // service took before switching to the primary's identity.
+        match self.check_lease(node_pubkey) {
+            Ok(()) => {
+                let epoch = self.lease(node_pubkey)?.epoch;
+                return self.load_before(node_pubkey, epoch + 1);
+            }
+            Err(err) if err.is_lost_lease() => (),
+            Err(err) => return Err(err),
+        }
+        let epoch = self.claim_next_epoch(node_pubkey)?;
+        self.leases.write().unwrap().insert(
+            *node_pubkey,
+            Lease {
+                epoch,
+                pruned: false,
+            },
+        );
+        self.load_before(node_pubkey, epoch)
+    }
+
+    fn peek(&self, node_pubkey: &Pubkey) -> Result<Tower> {
+        let epoch = self.latest_epoch(node_pubkey)?;
+        self.load_before(node_pubkey, epoch + 1)
+    }
+
+    fn store(&self, saved_tower: &SavedTowerVersions) -> Result<()> {
+        let node_pubkey = saved_tower.pubkey();
+        let lease = self.lease(&node_pubkey)?;
+        FileTowerStorage::store_to(&self.filename(&node_pubkey, lease.epoch), saved_tower)?;
+        self.check_lease(&node_pubkey)?;
+        if !lease.pruned {
+            self.prune(&node_pubkey, lease.epoch);
+            if let Some(lease) = self.leases.write().unwrap().get_mut(&node_pubkey) {
+                lease.pruned = true;
+            }
         }
-        fs::rename(&new_filename, &filename)?;
-        // self.path.parent().sync_all() hurts performance same as the above sync
         Ok(())
     }
+
+    fn check_lease(&self, node_pubkey: &Pubkey) -> Result<()> {
+        let epoch = self.lease(node_pubkey)?.epoch;
+        // Pruned by a later holder.
+        if !self.lease_filename(node_pubkey, epoch).try_exists()? {
+            return Err(TowerError::LostLease(*node_pubkey));
+        }
+        // Epochs are claimed in order, so any later claim that was not given
+        // back follows this one without a gap.
+        for later_epoch in epoch + 1.. {
+            if !self.lease_filename(node_pubkey, later_epoch).try_exists()? {
+                break;
+            }
+            if !self
+                .released_filename(node_pubkey, later_epoch)
+                .try_exists()?
+            {
+                return Err(TowerError::LostLease(*node_pubkey));
+            }
+        }
+        Ok(())
+    }
+
+    fn release(&self, node_pubkey: &Pubkey) -> Result<()> {
+        let epoch = self.lease(node_pubkey)?.epoch;
+        // The next holder would not see a tower stored under this lease.
+        if self.filename(node_pubkey, epoch).exists() {
+            return Err(TowerError::IoError(io::Error::new(
+                io::ErrorKind::Other,
+                format!("Tower lease for {node_pubkey} cannot be released after storing"),
+            )));
+        }
+        self.leases.write().unwrap().remove(node_pubkey);
+        // The lease file stays, so that the next claim takes a new epoch.
+        File::create(self.released_filename(node_pubkey, epoch))?.sync_all()?;
+        Ok(())
+    }
+
+    fn is_fenced(&self) -> bool {
+        true
+    }
+
+    fn is_leased(&self, node_pubkey: &Pubkey) -> Result<bool> {
+        Ok(self.latest_epoch(node_pubkey)? > 0 && !self.filename(node_pubkey, 0).try_exists()?)
+    }
 }
 
 pub struct EtcdTowerStorage {
     client: tokio::sync::Mutex<etcd_client::Client>,
     instance_id: [u8; 8],
+    // Instance that held each identity's lease before this one loaded its tower.
+    previous_instance_ids: RwLock<HashMap<Pubkey, Option<Vec<u8>>>>,
     runtime: tokio::runtime::Runtime,
 }
 
@@ -261,6 +542,7 @@ impl EtcdTowerStorage {
         Ok(Self {
             client: tokio::sync::Mutex::new(client),
             instance_id: solana_sdk::timing::timestamp().to_le_bytes(),
+            previous_instance_ids: RwLock::default(),
             runtime,
         })
     }
@@ -274,6 +556,12 @@ impl EtcdTowerStorage {
     fn etdc_to_tower_error(error: etcd_client::Error) -> TowerError {
         TowerError::IoError(io::Error::new(io::ErrorKind::Other, error.to_string()))
     }
+
+    fn deserialize_tower(data: &[u8], node_pubkey: &Pubkey) -> Result<Tower> {
+        bincode::deserialize_from(data)
+            .map_err(|e| e.into())
+            .and_then(|t: SavedTowerVersions| t.try_into_tower(node_pubkey))
+    }
 }
 
 impl TowerStorage for EtcdTowerStorage {
@@ -283,14 +571,29 @@ impl TowerStorage for EtcdTowerStorage {
         let txn = etcd_client::Txn::new().and_then(vec![etcd_client::TxnOp::put(
             instance_key.clone(),
             self.instance_id,
-            None,
+            Some(etcd_client::PutOptions::new().with_prev_key()),
         )]);
-        self.runtime
+        let response = self
+            .runtime
             .block_on(async { self.client.lock().await.txn(txn).await })
             .map_err(|err| {
                 error!("Failed to acquire etcd instance lock: {}", err);
                 Self::etdc_to_tower_error(err)
             })?;
+        let previous_instance_id = response.op_responses().into_iter().find_map(|op_response| {
+            if let etcd_client::TxnOpResponse::Put(put_response) = op_response {
+                put_response.prev_key().map(|kv| kv.value().to_vec())
+            } else {
+                None
+            }
+        });
+        // Reloading a tower whose lease this instance still holds keeps it.
+        if previous_instance_id.as_deref() != Some(&self.instance_id[..]) {
+            self.previous_instance_ids
+                .write()
+                .unwrap()
+                .insert(*node_pubkey, previous_instance_id);
+        }
 
         let txn = etcd_client::Txn::new()
             .when(vec![etcd_client::Compare::value(
@@ -309,18 +612,13 @@ impl TowerStorage for EtcdTowerStorage {
             })?;
 
         if !response.succeeded() {
-            return Err(TowerError::IoError(io::Error::new(
-                io::ErrorKind::Other,
-                format!("Lost etcd instance lock for {node_pubkey}"),
-            )));
+            return Err(TowerError::LostLease(*node_pubkey));
         }
 
         for op_response in response.op_responses() {
             if let etcd_client::TxnOpResponse::Get(get_response) = op_response {
                 if let Some(kv) = get_response.kvs().first() {
-                    return bincode::deserialize_from(kv.value())
-                        .map_err(|e| e.into())
-                        .and_then(|t: SavedTowerVersions| t.try_into_tower(node_pubkey));
+                    return Self::deserialize_tower(kv.value(), node_pubkey);
                 }
             }
         }
@@ -357,13 +655,90 @@ impl TowerStorage for EtcdTowerStorage {
             .map_err(Self::etdc_to_tower_error)?;
 
         if !response.succeeded() {
+            return Err(TowerError::LostLease(saved_tower.pubkey()));
+        }
+        // The previous instance would not see this tower if given the lease back.
+        self.previous_instance_ids
+            .write()
+            .unwrap()
+            .remove(&saved_tower.pubkey());
+        Ok(())
+    }
+
+    fn check_lease(&self, node_pubkey: &Pubkey) -> Result<()> {
+        let (instance_key, _) = Self::get_keys(node_pubkey);
+        let response = self
+            .runtime
+            .block_on(async { self.client.lock().await.get(instance_key, None).await })
+            .map_err(Self::etdc_to_tower_error)?;
+        match response.kvs().first() {
+            Some(kv) if kv.value() == self.instance_id => Ok(()),
+            _ => Err(TowerError::LostLease(*node_pubkey)),
+        }
+    }
+
+    fn peek(&self, node_pubkey: &Pubkey) -> Result<Tower> {
+        let (_, tower_key) = Self::get_keys(node_pubkey);
+        let response = self
+            .runtime
+            .block_on(async { self.client.lock().await.get(tower_key, None).await })
+            .map_err(|err| {
+                error!("Failed to read etcd saved tower: {}", err);
+                Self::etdc_to_tower_error(err)
+            })?;
+        match response.kvs().first() {
+            Some(kv) => Self::deserialize_tower(kv.value(), node_pubkey),
+            None => Err(TowerError::IoError(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("No saved tower for {node_pubkey}"),
+            ))),
+        }
+    }
+
+    fn is_leased(&self, node_pubkey: &Pubkey) -> Result<bool> {
+        let (instance_key, _) = Self::get_keys(node_pubkey);
+        let response = self
+            .runtime
+            .block_on(async { self.client.lock().await.get(instance_key, None).await })
+            .map_err(Self::etdc_to_tower_error)?;
+        Ok(!response.kvs().is_empty())
+    }
+
+    fn release(&self, node_pubkey: &Pubkey) -> Result<()> {
+        let (instance_key, _) = Self::get_keys(node_pubkey);
+        let Some(previous_instance_id) = self
+            .previous_instance_ids
+            .write()
+            .unwrap()
+            .remove(node_pubkey)
+        else {
             return Err(TowerError::IoError(io::Error::new(
                 io::ErrorKind::Other,
-                format!("Lost etcd instance lock for {}", saved_tower.pubkey()),
+                format!("Tower lease for {node_pubkey} cannot be released after storing"),
             )));
-        }
+        };
+        let restore = match previous_instance_id {
+            Some(previous_instance_id) => {
+                etcd_client::TxnOp::put(instance_key.clone(), previous_instance_id, None)
+            }
+            None => etcd_client::TxnOp::delete(instance_key.clone(), None),
+        };
+        let txn = etcd_client::Txn::new()
+            .when(vec![etcd_client::Compare::value(
+                instance_key,
+                etcd_client::CompareOp::Equal,
+                self.instance_id,
+            )])
+            .and_then(vec![restore]);
+        self.runtime
+            .block_on(async { self.client.lock().await.txn(txn).await })
+            .map_err(Self::etdc_to_tower_error)?;
         Ok(())
     }
+
+    fn is_fenced(&self) -> bool {
+        true
+    }
 }
 
 #[cfg(test)]
@@ -419,4 +794,123 @@ pub mod test {
         assert_eq!(loaded.vote_state.root_slot, Some(1));
         assert_eq!(loaded.stray_restored_slot(), None);
     }
+
+    #[test]
+    fn test_shared_file_tower_storage_lease() {
+        let tower_path = TempDir::new().unwrap();
+        let identity_keypair = Keypair::new();
+        let node_pubkey = identity_keypair.pubkey();
+        let tower = Tower {
+            node_pubkey,
+            ..Tower::default()
+        };
+        let primary = SharedFileTowerStorage::new(tower_path.path().to_path_buf());
+        let standby = SharedFileTowerStorage::new(tower_path.path().to_path_buf());
+        assert!(primary.is_fenced());
+
+        // Storing requires the lease, which is taken by loading the tower,
+        // even if there is no saved tower yet.
+        assert_matches!(
+            tower.save(&primary, &identity_keypair),
+            Err(TowerError::LostLease(_))
+        );
+        assert_matches!(
+            Tower::restore(&primary, &node_pubkey),
+            Err(TowerError::IoError(_))
+        );
+        tower.save(&primary, &identity_keypair).unwrap();
+
+        // Once the standby loads the tower, the primary can no longer store it.
+        let loaded = Tower::restore(&standby, &node_pubkey).unwrap();
+        assert_eq!(loaded.node_pubkey, node_pubkey);
+        assert_matches!(
+            primary.check_lease(&node_pubkey),
+            Err(TowerError::LostLease(_))
+        );
+        assert_matches!(
+            tower.save(&primary, &identity_keypair),
+            Err(TowerError::LostLease(_))
+        );
+        standby.check_lease(&node_pubkey).unwrap();
+        let mut standby_tower = loaded;
+        standby_tower.record_vote(1, Hash::new_unique());
+        standby_tower.save(&standby, &identity_keypair).unwrap();
+
+        // The primary's late store went to its own epoch, not over the
+        // standby's tower.
+        tower.save(&primary, &identity_keypair).unwrap_err();
+        let latest = primary.peek(&node_pubkey).unwrap();
+        assert_eq!(latest.last_voted_slot(), Some(1));
+        assert_eq!(
+            Tower::restore(&primary, &node_pubkey)
+                .unwrap()
+                .last_voted_slot(),
+            Some(1)
+        );
+    }
+
+    #[test]
+    fn test_shared_file_tower_storage_release() {
+        let tower_path = TempDir::new().unwrap();
+        let identity_keypair = Keypair::new();
+        let node_pubkey = identity_keypair.pubkey();
+        let tower = Tower {
+            node_pubkey,
+            ..Tower::default()
+        };
+        let primary = SharedFileTowerStorage::new(tower_path.path().to_path_buf());
+        let standby = SharedFileTowerStorage::new(tower_path.path().to_path_buf());
+        assert!(Tower::restore(&primary, &node_pubkey).is_err());
+        tower.save(&primary, &identity_keypair).unwrap();
+
+        // Releasing the lease hands it back to the primary.
+        Tower::restore(&standby, &node_pubkey).unwrap();
+        standby.release(&node_pubkey).unwrap();
+        assert_matches!(
+            standby.check_lease(&node_pubkey),
+            Err(TowerError::LostLease(_))
+        );
+        primary.check_lease(&node_pubkey).unwrap();
+        tower.save(&primary, &identity_keypair).unwrap();
+
+        // But not once a tower was stored under it.
+        Tower::restore(&standby, &node_pubkey).unwrap();
+        tower.save(&standby, &identity_keypair).unwrap();
+        assert_matches!(standby.release(&node_pubkey), Err(TowerError::IoError(_)));
+        assert_matches!(
+            primary.check_lease(&node_pubkey),
+            Err(TowerError::LostLease(_))
+        );
+    }
+
+    #[test]
+    fn test_shared_file_tower_storage_is_leased() {
+        let tower_path = TempDir::new().unwrap();
+        let identity_keypair = Keypair::new();
+        let node_pubkey = identity_keypair.pubkey();
+        let tower = Tower {
+            node_pubkey,
+            ..Tower::default()
+        };
+        let unfenced = FileTowerStorage::new(tower_path.path().to_path_buf());
+        let primary = SharedFileTowerStorage::new(tower_path.path().to_path_buf());
+        tower.save(&unfenced, &identity_keypair).unwrap();
+        assert!(!primary.is_leased(&node_pubkey).unwrap());
+
+        // The tower stored without a lease is pruned once one is stored under
+        // the lease.
+        Tower::restore(&primary, &node_pubkey).unwrap();
+        assert!(!primary.is_leased(&node_pubkey).unwrap());
+        tower.save(&primary, &identity_keypair).unwrap();
+        assert!(primary.is_leased(&node_pubkey).unwrap());
+
+        // Reloading keeps the lease.
+        Tower::restore(&primary, &node_pubkey).unwrap();
+        assert_eq!(primary.latest_epoch(&node_pubkey).unwrap(), 1);
+        tower.save(&primary, &identity_keypair).unwrap();
+
+        // An instance storing without a lease is not fenced off by a takeover.
+        tower.save(&unfenced, &identity_keypair).unwrap();
+        assert!(!primary.is_leased(&node_pubkey).unwrap());
+    }
 }
@@ -35,6 +35,7 @@ mod shred_fetch_stage;
 pub mod sigverify;
 pub mod sigverify_stage;
 pub mod snapshot_packager_service;
+pub mod standby_service;
 pub mod staked_nodes_updater_service;
 pub mod stats_reporter_service;
 pub mod system_monitor_service;
@@ -288,6 +288,9 @@ pub struct ReplayStageConfig {
     pub wait_for_vote_to_start_leader: bool,
     pub ancestor_hashes_replay_update_sender: AncestorHashesReplayUpdateSender,
     pub tower_storage: Arc<dyn TowerStorage>,
+    // Set while this instance holds the lease on its identity's tower, if
+    // the lease is checked. Leader slots are skipped while it is not held.
+    pub tower_lease_held: Option<Arc<AtomicBool>>,
     // Stops voting until this slot has been reached. Should be used to avoid
     // duplicate voting which can lead to slashing.
     pub wait_to_vote_slot: Option<Slot>,
@@ -573,6 +576,7 @@ impl ReplayStage {
             wait_for_vote_to_start_leader,
             ancestor_hashes_replay_update_sender,
             tower_storage,
+            tower_lease_held,
             wait_to_vote_slot,
             replay_slots_concurrently,
         } = config;
@@ -1146,6 +1150,7 @@ impl ReplayStage {
                         &banking_tracer,
                         has_new_vote_been_rooted,
                         transaction_status_sender.is_some(),
+                        tower_lease_held.as_deref(),
                     );
 
                     let poh_bank = poh_recorder.read().unwrap().bank();
@@ -2005,6 +2010,7 @@ impl ReplayStage {
         banking_tracer: &Arc<BankingTracer>,
         has_new_vote_been_rooted: bool,
         track_transaction_indexes: bool,
+        tower_lease_held: Option<&AtomicBool>,
     ) {
         // all the individual calls to poh_recorder.read() are designed to
         // increase granularity, decrease contention
@@ -2100,6 +2106,15 @@ impl ReplayStage {
                 return;
             }
 
+            // Another instance that took over this identity would produce
+            // blocks for the same slots.
+            if let Some(tower_lease_held) = tower_lease_held {
+                if !tower_lease_held.load(Ordering::Relaxed) {
+                    warn!("Tower lease not held, skipping leader slot {poh_slot}");
+                    return;
+                }
+            }
+
             let root_slot = bank_forks.read().unwrap().root();
             datapoint_info!("replay_stage-my_leader_slot", ("slot", poh_slot, i64),);
             info!(
@@ -0,0 +1,418 @@
+//! The `standby_service` lets a validator run as a hot spare for a primary
+//! node that holds the staked identity.
+//!
+//! The standby runs with a different identity, so it neither votes nor
+//! produces blocks, and follows the primary's votes through gossip. If the
+//! primary stops voting for `max_missed_slots` slots, the standby loads the
+//! primary's tower and switches to its identity; replay picks up the new
+//! identity and resumes voting from the restored tower.
+//!
+//! Gossip alone cannot tell a dead primary from one that is only cut off
+//! from gossip, so the primary and the standby must share a fenced
+//! [`TowerStorage`] (see [`TowerStorage::is_fenced`]). Loading the primary's
+//! tower takes over its lease: from then on every tower save on the primary
+//! fails, and since a vote is only sent after its tower has been saved, the
+//! primary exits without casting another vote.
+//!
+//! The takeover is refused if the primary does not store its tower under a
+//! lease (see [`TowerStorage::is_leased`]), such as when it runs with a
+//! storage that is not fenced, since taking the lease would not stop it. It
+//! is also refused if the primary's tower is older than the last vote
+//! observed from the primary in gossip, which means the primary voted without
+//! saving to the shared storage. Both are checked before the lease is taken,
+//! so that a refusal leaves a primary that is still running untouched, and
+//! the takeover is retried after another `max_missed_slots`.
+//!
+//! A primary that keeps producing blocks after the takeover would produce
+//! duplicate blocks for the same slots, so the primary should run with
+//! `check_tower_lease`: its [`TowerLeaseService`] then stops block
+//! production and exits the validator once the lease is lost.
+
+use {
+    crate::{
+        admin_rpc_post_init::AdminRpcRequestMetadataPostInit,
+        consensus::{tower_storage::TowerStorage, Tower, TowerError},
+    },
+    solana_gossip::{cluster_info::ClusterInfo, crds::Cursor},
+    solana_sdk::{
+        clock::{Slot, DEFAULT_MS_PER_SLOT},
+        pubkey::Pubkey,
+        signature::{Keypair, Signer},
+    },
+    solana_vote::vote_parser,
+    std::{
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            Arc,
+        },
+        thread::{self, sleep, Builder, JoinHandle},
+        time::Duration,
+    },
+    thiserror::Error,
+};
+
+pub const DEFAULT_STANDBY_MAX_MISSED_SLOTS: Slot = 64;
+
+#[derive(Clone)]
+pub struct StandbyConfig {
+    /// Identity of the primary node, assumed once the standby takes over.
+    pub primary_identity: Arc<Keypair>,
+    /// Number of slots without a vote from the primary before taking over.
+    pub max_missed_slots: Slot,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum StandbyError {
+    #[error("tower last voted slot {tower_slot:?} is behind primary's last vote {primary_slot}")]
+    StaleTower {
+        tower_slot: Option<Slot>,
+        primary_slot: Slot,
+    },
+    #[error("unable to load primary's tower: {0}")]
+    TowerUnavailable(String),
+    #[error("primary does not store its tower under a lease")]
+    PrimaryNotLeased,
+}
+
+/// Tracks the primary's liveness as seen through gossip votes.
+#[derive(Debug)]
+struct StandbyMonitor {
+    primary: Pubkey,
+    max_missed_slots: Slot,
+    // Highest slot the primary was observed voting on.
+    primary_last_vote: Option<Slot>,
+    // Slot from which missed slots are counted; the highest slot seen when
+    // the primary last made progress, when monitoring started, or when the
+    // last takeover attempt failed.
+    last_progress_slot: Option<Slot>,
+}
+
+impl StandbyMonitor {
+    fn new(primary: Pubkey, max_missed_slots: Slot) -> Self {
+        Self {
+            primary,
+            max_missed_slots,
+            primary_last_vote: None,
+            last_progress_slot: None,
+        }
+    }
+
+    fn observe_vote(&mut self, node: &Pubkey, slot: Slot, highest_slot: Slot) {
+        if node != &self.primary || self.primary_last_vote >= Some(slot) {
+            return;
+        }
+        self.primary_last_vote = Some(slot);
+        self.last_progress_slot = Some(highest_slot);
+    }
+
+    fn should_take_over(&mut self, highest_slot: Slot) -> bool {
+        let last_progress_slot = *self.last_progress_slot.get_or_insert(highest_slot);
+        highest_slot.saturating_sub(last_progress_slot) >= self.max_missed_slots
+    }
+
+    // Waits another max_missed_slots before the next takeover attempt.
+    fn defer_take_over(&mut self, highest_slot: Slot) {
+        self.last_progress_slot = Some(highest_slot);
+    }
+
+    // Voting from a tower that has not seen the primary's latest vote may
+    // violate its lockouts, so the tower must be at least as recent.
+    fn check_tower(&self, tower_last_voted_slot: Option<Slot>) -> Result<(), StandbyError> {
+        match self.primary_last_vote {
+            Some(primary_slot) if tower_last_voted_slot < Some(primary_slot) => {
+                Err(StandbyError::StaleTower {
+                    tower_slot: tower_last_voted_slot,
+                    primary_slot,
+                })
+            }
+            _ => Ok(()),
+        }
+    }
+}
+
+pub struct StandbyService {
+    thread_hdl: JoinHandle<()>,
+}
+
+impl StandbyService {
+    pub fn new(
+        config: StandbyConfig,
+        post_init: AdminRpcRequestMetadataPostInit,
+        tower_storage: Arc<dyn TowerStorage>,
+        exit: Arc<AtomicBool>,
+    ) -> Self {
+        assert!(
+            tower_storage.is_fenced(),
+            "standby requires a fenced tower storage"
+        );
+        let thread_hdl = Builder::new()
+            .name("solStandbySvc".to_string())
+            .spawn(move || {
+                let primary = config.primary_identity.pubkey();
+                info!(
+                    "standby for {primary}, taking over after {} missed slots",
+                    config.max_missed_slots
+                );
+                let mut monitor = StandbyMonitor::new(primary, config.max_missed_slots);
+                let mut cursor = Cursor::default();
+                while !exit.load(Ordering::Relaxed) {
+                    let highest_slot = post_init.bank_forks.read().unwrap().highest_slot();
+                    let (labels, votes) = post_init.cluster_info.get_votes_with_labels(&mut cursor);
+                    for (label, vote) in labels.iter().zip(votes.iter()) {
+                        let Some((_, vote, ..)) = vote_parser::parse_vote_transaction(vote) else {
+                            continue;
+                        };
+                        if let Some(slot) = vote.last_voted_slot() {
+                            monitor.observe_vote(&label.pubkey(), slot, highest_slot);
+                        }
+                    }
+                    if monitor.should_take_over(highest_slot) {
+                        match Self::take_over(&config, &monitor, &post_init, tower_storage.as_ref())
+                        {
+                            Ok(()) => {
+                                datapoint_warn!(
+                                    "standby-takeover",
+                                    ("primary", primary.to_string(), String),
+                                    ("slot", highest_slot, i64),
+                                    ("primary_last_vote", monitor.primary_last_vote, Option<i64>),
+                                );
+                                break;
+                            }
+                            Err(
+                                err @ (StandbyError::StaleTower { .. }
+                                | StandbyError::PrimaryNotLeased),
+                            ) => {
+                                error!(
+                                    "standby: refusing to take over for {primary}, retrying in \
+                                     {} slots: {err}",
+                                    config.max_missed_slots
+                                );
+                                datapoint_error!(
+                                    "standby-takeover-refused",
+                                    ("primary", primary.to_string(), String),
+                                    ("error", err.to_string(), String),
+                                );
+                                monitor.defer_take_over(highest_slot);
+                            }
+                            Err(err) => {
+                                warn!(
+                                    "standby: takeover for {primary} failed, retrying in {} \
+                                     slots: {err}",
+                                    config.max_missed_slots
+                                );
+                                monitor.defer_take_over(highest_slot);
+                            }
+                        }
+                    }
+                    sleep(Duration::from_millis(DEFAULT_MS_PER_SLOT));
+                }
+            })
+            .unwrap();
+        Self { thread_hdl }
+    }
+
+    fn take_over(
+        config: &StandbyConfig,
+        monitor: &StandbyMonitor,
+        post_init: &AdminRpcRequestMetadataPostInit,
+        tower_storage: &dyn TowerStorage,
+    ) -> Result<(), StandbyError> {
+        Self::restore_primary_tower(monitor, tower_storage)?;
+        post_init.set_identity(config.primary_identity.clone());
+        Ok(())
+    }
+
+    // The tower is checked before taking the primary's lease, and again
+    // after in case the primary stored a newer one in between. Only then is
+    // the lease given back if the tower cannot be voted from, which fails a
+    // store of the primary in the meantime.
+    fn restore_primary_tower(
+        monitor: &StandbyMonitor,
+        tower_storage: &dyn TowerStorage,
+    ) -> Result<Tower, StandbyError> {
+        let unavailable = |err: TowerError| StandbyError::TowerUnavailable(err.to_string());
+        if !tower_storage
+            .is_leased(&monitor.primary)
+            .map_err(unavailable)?
+        {
+            return Err(StandbyError::PrimaryNotLeased);
+        }
+        let tower = tower_storage.peek(&monitor.primary).map_err(unavailable)?;
+        monitor.check_tower(tower.last_voted_slot())?;
+
+        // Replay reloads the tower under the lease taken here once the
+        // identity is switched.
+        let result = Tower::restore(tower_storage, &monitor.primary)
+            .map_err(unavailable)
+            .and_then(|tower| {
+                monitor.check_tower(tower.last_voted_slot())?;
+                Ok(tower)
+            });
+        if result.is_err() {
+            if let Err(err) = tower_storage.release(&monitor.primary) {
+                warn!(
+                    "standby: unable to release tower lease for {}: {err}",
+                    monitor.primary
+                );
+            }
+        }
+        result
+    }
+
+    pub fn join(self) -> thread::Result<()> {
+        self.thread_hdl.join()
+    }
+}
+
+/// Refreshes whether this instance still holds the lease on its identity's
+/// tower, so that replay can skip leader slots without waiting on the tower
+/// storage. Once the lease is lost the validator exits, since another
+/// instance is then running with the same identity.
+pub struct TowerLeaseService {
+    thread_hdl: JoinHandle<()>,
+}
+
+impl TowerLeaseService {
+    pub fn new(
+        cluster_info: Arc<ClusterInfo>,
+        tower_storage: Arc<dyn TowerStorage>,
+        lease_held: Arc<AtomicBool>,
+        exit: Arc<AtomicBool>,
+    ) -> Self {
+        let thread_hdl = Builder::new()
+            .name("solTowerLease".to_string())
+            .spawn(move || {
+                while !exit.load(Ordering::Relaxed) {
+                    // The identity changes once a standby takes over.
+                    let node_pubkey = cluster_info.id();
+                    match tower_storage.check_lease(&node_pubkey) {
+                        Ok(()) => lease_held.store(true, Ordering::Relaxed),
+                        Err(err) if err.is_lost_lease() => {
+                            lease_held.store(false, Ordering::Relaxed);
+                            error!("{err}, exiting");
+                            exit.store(true, Ordering::Relaxed);
+                            break;
+                        }
+                        Err(err) => {
+                            if lease_held.swap(false, Ordering::Relaxed) {
+                                warn!("Unable to verify tower lease for {node_pubkey}: {err}");
+                            }
+                        }
+                    }
+                    sleep(Duration::from_millis(DEFAULT_MS_PER_SLOT));
+                }
+            })
+            .unwrap();
+        Self { thread_hdl }
+    }
+
+    pub fn join(self) -> thread::Result<()> {
+        self.thread_hdl.join()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        crate::consensus::tower_storage::{FileTowerStorage, SharedFileTowerStorage},
+        solana_sdk::hash::Hash,
+        tempfile::TempDir,
+    };
+
+    #[test]
+    fn test_standby_monitor_take_over() {
+        let primary = Pubkey::new_unique();
+        let mut monitor = StandbyMonitor::new(primary, 10);
+        // Missed slots are counted from when monitoring started.
+        assert!(!monitor.should_take_over(100));
+        assert!(!monitor.should_take_over(109));
+        assert!(monitor.should_take_over(110));
+
+        // Votes from other nodes are ignored.
+        monitor.observe_vote(&Pubkey::new_unique(), 110, 110);
+        assert!(monitor.should_take_over(110));
+
+        monitor.observe_vote(&primary, 108, 110);
+        assert!(!monitor.should_take_over(119));
+        // Older votes do not count as progress.
+        monitor.observe_vote(&primary, 105, 119);
+        assert!(monitor.should_take_over(120));
+        assert_eq!(monitor.primary_last_vote, Some(108));
+    }
+
+    #[test]
+    fn test_standby_monitor_check_tower() {
+        let primary = Pubkey::new_unique();
+        let mut monitor = StandbyMonitor::new(primary, 10);
+        assert_eq!(monitor.check_tower(None), Ok(()));
+
+        monitor.observe_vote(&primary, 50, 52);
+        assert_eq!(
+            monitor.check_tower(None),
+            Err(StandbyError::StaleTower {
+                tower_slot: None,
+                primary_slot: 50
+            })
+        );
+        assert_eq!(
+            monitor.check_tower(Some(49)),
+            Err(StandbyError::StaleTower {
+                tower_slot: Some(49),
+                primary_slot: 50
+            })
+        );
+        assert_eq!(monitor.check_tower(Some(50)), Ok(()));
+        assert_eq!(monitor.check_tower(Some(51)), Ok(()));
+    }
+
+    #[test]
+    fn test_standby_restore_primary_tower() {
+        let tower_path = TempDir::new().unwrap();
+        let primary_keypair = Keypair::new();
+        let primary = primary_keypair.pubkey();
+        let unfenced_storage = FileTowerStorage::new(tower_path.path().to_path_buf());
+        let primary_storage = SharedFileTowerStorage::new(tower_path.path().to_path_buf());
+        let standby_storage = SharedFileTowerStorage::new(tower_path.path().to_path_buf());
+
+        let mut tower = Tower {
+            node_pubkey: primary,
+            ..Tower::default()
+        };
+        tower.record_vote(5, Hash::new_unique());
+        let mut monitor = StandbyMonitor::new(primary, 10);
+
+        // Taking the lease would not stop a primary storing without one.
+        tower.save(&unfenced_storage, &primary_keypair).unwrap();
+        assert_eq!(
+            StandbyService::restore_primary_tower(&monitor, &standby_storage).unwrap_err(),
+            StandbyError::PrimaryNotLeased
+        );
+        Tower::restore(&primary_storage, &primary).unwrap();
+        tower.save(&primary_storage, &primary_keypair).unwrap();
+
+        // The primary was seen voting past its saved tower, so the standby
+        // must not vote from it, and leaves the primary's lease alone.
+        monitor.observe_vote(&primary, 10, 10);
+        assert_eq!(
+            StandbyService::restore_primary_tower(&monitor, &standby_storage).unwrap_err(),
+            StandbyError::StaleTower {
+                tower_slot: Some(5),
+                primary_slot: 10
+            }
+        );
+        primary_storage.check_lease(&primary).unwrap();
+        tower.record_vote(10, Hash::new_unique());
+        tower.save(&primary_storage, &primary_keypair).unwrap();
+
+        // Once the tower catches up, the standby takes it over and fences
+        // off the primary.
+        let restored = StandbyService::restore_primary_tower(&monitor, &standby_storage).unwrap();
+        assert_eq!(restored.last_voted_slot(), Some(10));
+        assert!(tower.save(&primary_storage, &primary_keypair).is_err());
+
+        // Replay reloading the tower keeps the standby's lease.
+        Tower::restore(&standby_storage, &primary).unwrap();
+        restored.save(&standby_storage, &primary_keypair).unwrap();
+    }
+}
@@ -113,6 +113,7 @@ impl Tvu {
         poh_recorder: &Arc<RwLock<PohRecorder>>,
         tower: Tower,
         tower_storage: Arc<dyn TowerStorage>,
+        tower_lease_held: Option<Arc<AtomicBool>>,
         leader_schedule_cache: &Arc<LeaderScheduleCache>,
         exit: Arc<AtomicBool>,
         block_commitment_cache: Arc<RwLock<BlockCommitmentCache>>,
@@ -264,6 +265,7 @@ impl Tvu {
             wait_for_vote_to_start_leader: tvu_config.wait_for_vote_to_start_leader,
             ancestor_hashes_replay_update_sender,
             tower_storage: tower_storage.clone(),
+            tower_lease_held,
             wait_to_vote_slot,
             replay_slots_concurrently: tvu_config.replay_slots_concurrently,
         };
@@ -471,6 +473,7 @@ pub mod tests {
             &poh_recorder,
             Tower::default(),
             Arc::new(FileTowerStorage::default()),
+            None,
             &leader_schedule_cache,
             exit.clone(),
             block_commitment_cache,
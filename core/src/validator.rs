@@ -20,6 +20,7 @@ use {
         sample_performance_service::SamplePerformanceService,
         sigverify,
         snapshot_packager_service::SnapshotPackagerService,
+        standby_service::{StandbyConfig, StandbyService, TowerLeaseService},
         stats_reporter_service::StatsReporterService,
         system_monitor_service::{
             verify_net_stats_access, SystemMonitorService, SystemMonitorStatsReportConfig,
@@ -213,6 +214,8 @@ pub struct ValidatorConfig {
     /// Specifies which plugins to start up with
     pub on_start_geyser_plugin_config_files: Option<Vec<PathBuf>>,
     pub rpc_addrs: Option<(SocketAddr, SocketAddr)>, // (JsonRpc, JsonRpcPubSub)
+    /// Also serve the ip echo server's JSON endpoints over HTTP on this address
+    pub ip_echo_http_addr: Option<SocketAddr>,
     pub pubsub_config: PubSubConfig,
     pub snapshot_config: SnapshotConfig,
     pub max_ledger_shreds: Option<u64>,
@@ -268,6 +271,12 @@ pub struct ValidatorConfig {
     pub use_snapshot_archives_at_startup: UseSnapshotArchivesAtStartup,
     pub wen_restart_proto_path: Option<PathBuf>,
     pub unified_scheduler_handler_threads: Option<usize>,
+    /// Run as a hot spare for the given primary identity, see [`StandbyService`].
+    pub standby_config: Option<StandbyConfig>,
+    /// Skip leader slots unless this instance holds the lease on its tower,
+    /// and exit once it is lost. Implied by `standby_config`; set it on the
+    /// primary of a standby.
+    pub check_tower_lease: bool,
 }
 
 impl Default for ValidatorConfig {
@@ -284,6 +293,7 @@ impl Default for ValidatorConfig {
             rpc_config: JsonRpcConfig::default(),
             on_start_geyser_plugin_config_files: None,
             rpc_addrs: None,
+            ip_echo_http_addr: None,
             pubsub_config: PubSubConfig::default(),
             snapshot_config: SnapshotConfig::new_load_only(),
             broadcast_stage_type: BroadcastStageType::Standard,
@@ -336,6 +346,8 @@ impl Default for ValidatorConfig {
             use_snapshot_archives_at_startup: UseSnapshotArchivesAtStartup::default(),
             wen_restart_proto_path: None,
             unified_scheduler_handler_threads: None,
+            standby_config: None,
+            check_tower_lease: false,
         }
     }
 }
@@ -456,6 +468,8 @@ pub struct Validator {
     entry_notifier_service: Option<EntryNotifierService>,
     system_monitor_service: Option<SystemMonitorService>,
     sample_performance_service: Option<SamplePerformanceService>,
+    standby_service: Option<StandbyService>,
+    tower_lease_service: Option<TowerLeaseService>,
     poh_timing_report_service: PohTimingReportService,
     stats_reporter_service: StatsReporterService,
     gossip_service: GossipService,
@@ -512,6 +526,19 @@ impl Validator {
                 .map_err(|err| format!("Failed to access network stats: {err:?}"))?;
         }
 
+        if config.standby_config.is_some() && !config.tower_storage.is_fenced() {
+            return Err(
+                "Standby mode requires a tower storage shared with the primary, \
+                 such as etcd or shared-file"
+                    .to_string(),
+            );
+        }
+        if config.check_tower_lease && !config.tower_storage.is_fenced() {
+            return Err("Checking the tower lease requires a fenced tower storage, \
+                 such as etcd or shared-file"
+                .to_string());
+        }
+
         let mut bank_notification_senders = Vec::new();
 
         let exit = Arc::new(AtomicBool::new(false));
@@ -1075,6 +1102,19 @@ impl Validator {
                 Some(node.info.shred_version()),
             )),
         };
+        if let Some(ip_echo_http_addr) = config.ip_echo_http_addr {
+            let Some(ip_echo_server) = &ip_echo_server else {
+                return Err("ip echo HTTP server requires the ip echo server".to_string());
+            };
+            let tcp_listener = std::net::TcpListener::bind(ip_echo_http_addr).map_err(|err| {
+                format!("Failed to bind ip echo HTTP server to {ip_echo_http_addr}: {err}")
+            })?;
+            solana_net_utils::ip_echo_http_server(
+                ip_echo_server,
+                tcp_listener,
+                Some(node.info.shred_version()),
+            );
+        }
 
         let (stats_reporter_sender, stats_reporter_receiver) = unbounded();
 
@@ -1266,6 +1306,20 @@ impl Validator {
         let cluster_slots =
             Arc::new(crate::cluster_slots_service::cluster_slots::ClusterSlots::default());
 
+        let (tower_lease_service, tower_lease_held) =
+            if config.standby_config.is_some() || config.check_tower_lease {
+                let tower_lease_held = Arc::<AtomicBool>::default();
+                let tower_lease_service = TowerLeaseService::new(
+                    cluster_info.clone(),
+                    config.tower_storage.clone(),
+                    tower_lease_held.clone(),
+                    exit.clone(),
+                );
+                (Some(tower_lease_service), Some(tower_lease_held))
+            } else {
+                (None, None)
+            };
+
         let tvu = Tvu::new(
             vote_account,
             authorized_voter_keypairs,
@@ -1283,6 +1337,7 @@ impl Validator {
             &poh_recorder,
             tower,
             config.tower_storage.clone(),
+            tower_lease_held,
             &leader_schedule_cache,
             exit.clone(),
             block_commitment_cache,
@@ -1360,7 +1415,7 @@ impl Validator {
             entry_notification_sender,
             blockstore.clone(),
             &config.broadcast_stage_type,
-            exit,
+            exit.clone(),
             node.info.shred_version(),
             vote_tracker,
             bank_forks.clone(),
@@ -1397,7 +1452,7 @@ impl Validator {
         *start_progress.write().unwrap() = ValidatorStartProgress::Running;
         key_notifies.push(connection_cache);
 
-        *admin_rpc_service_post_init.write().unwrap() = Some(AdminRpcRequestMetadataPostInit {
+        let post_init = AdminRpcRequestMetadataPostInit {
             bank_forks: bank_forks.clone(),
             cluster_info: cluster_info.clone(),
             vote_account: *vote_account,
@@ -1406,7 +1461,16 @@ impl Validator {
             repair_socket: Arc::new(node.sockets.repair),
             outstanding_repair_requests,
             cluster_slots,
+        };
+        let standby_service = config.standby_config.clone().map(|standby_config| {
+            StandbyService::new(
+                standby_config,
+                post_init.clone(),
+                config.tower_storage.clone(),
+                exit.clone(),
+            )
         });
+        *admin_rpc_service_post_init.write().unwrap() = Some(post_init);
 
         Ok(Self {
             stats_reporter_service,
@@ -1422,6 +1486,8 @@ impl Validator {
             entry_notifier_service,
             system_monitor_service,
             sample_performance_service,
+            standby_service,
+            tower_lease_service,
             poh_timing_report_service,
             snapshot_packager_service,
             completed_data_sets_service,
@@ -1542,6 +1608,14 @@ impl Validator {
                 .expect("sample_performance_service");
         }
 
+        if let Some(standby_service) = self.standby_service {
+            standby_service.join().expect("standby_service");
+        }
+
+        if let Some(tower_lease_service) = self.tower_lease_service {
+            tower_lease_service.join().expect("tower_lease_service");
+        }
+
         if let Some(entry_notifier_service) = self.entry_notifier_service {
             entry_notifier_service
                 .join()
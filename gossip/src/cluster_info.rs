@@ -397,6 +397,25 @@ fn retain_staked(values: &mut Vec<CrdsValue>, stakes: &HashMap<Pubkey, u64>) {
     })
 }
 
+// Returns false for contact-infos advertising an address that can never reach
+// the node, so that they are neither inserted into crds nor propagated.
+fn is_routable_contact_info(value: &CrdsValue) -> bool {
+    match &value.data {
+        CrdsData::ContactInfo(node) => node.sanitize_addrs().is_ok(),
+        CrdsData::LegacyContactInfo(node) => node.sanitize_addrs().is_ok(),
+        _ => true,
+    }
+}
+
+fn retain_routable_contact_infos(values: &mut Vec<CrdsValue>, dropped_counter: &Counter) {
+    let num_values = values.len();
+    values.retain(is_routable_contact_info);
+    let num_dropped = num_values - values.len();
+    if num_dropped != 0 {
+        dropped_counter.add_relaxed(num_dropped as u64);
+    }
+}
+
 impl ClusterInfo {
     pub fn new(
         contact_info: ContactInfo,
@@ -1801,7 +1820,6 @@ impl ClusterInfo {
                     let value = CrdsValue::new_signed(value, &self.keypair());
                     self.push_message(value);
                 }
-                let mut generate_pull_requests = true;
                 loop {
                     let start = timestamp();
                     if self.contact_debug_interval != 0
@@ -1833,6 +1851,10 @@ impl ClusterInfo {
                         }
                         None => (Arc::default(), None),
                     };
+                    let generate_pull_requests = self.gossip.pull.should_generate_pull_requests(
+                        start,
+                        self.gossip.crds.read().unwrap().len(),
+                    );
                     let _ = self.run_gossip(
                         &thread_pool,
                         gossip_validators.as_ref(),
@@ -1863,7 +1885,6 @@ impl ClusterInfo {
                         let time_left = GOSSIP_SLEEP_MILLIS - elapsed;
                         sleep(Duration::from_millis(time_left));
                     }
-                    generate_pull_requests = !generate_pull_requests;
                 }
             })
             .unwrap()
@@ -2434,14 +2455,26 @@ impl ClusterInfo {
         for (from_addr, packet) in packets {
             match packet {
                 Protocol::PullRequest(filter, caller) => {
-                    pull_requests.push((from_addr, filter, caller))
+                    if is_routable_contact_info(&caller) {
+                        pull_requests.push((from_addr, filter, caller))
+                    } else {
+                        self.stats.bad_contact_info_pull_request.add_relaxed(1);
+                    }
                 }
                 Protocol::PullResponse(_, mut data) => {
                     check_duplicate_instance(&data)?;
+                    retain_routable_contact_infos(
+                        &mut data,
+                        &self.stats.bad_contact_info_pull_response,
+                    );
                     pull_responses.append(&mut data);
                 }
-                Protocol::PushMessage(from, data) => {
+                Protocol::PushMessage(from, mut data) => {
                     check_duplicate_instance(&data)?;
+                    retain_routable_contact_infos(
+                        &mut data,
+                        &self.stats.bad_contact_info_push_message,
+                    );
                     push_messages.push((from, data));
                 }
                 Protocol::PruneMessage(_from, data) => prune_messages.push(data),
@@ -90,6 +90,9 @@ impl<'a, T> Drop for TimedGuard<'a, T> {
 #[derive(Default)]
 pub struct GossipStats {
     pub(crate) all_tvu_peers: Counter,
+    pub(crate) bad_contact_info_pull_request: Counter,
+    pub(crate) bad_contact_info_pull_response: Counter,
+    pub(crate) bad_contact_info_push_message: Counter,
     pub(crate) bad_prune_destination: Counter,
     pub(crate) entrypoint2: Counter,
     pub(crate) entrypoint: Counter,
@@ -376,6 +379,12 @@ pub(crate) fn submit_gossip_stats(
         ),
         ("epoch_slots_lookup", stats.epoch_slots_lookup.clear(), i64),
         ("new_pull_requests", stats.new_pull_requests.clear(), i64),
+        (
+            "pull_request_interval_ms",
+            gossip.pull.pull_request_interval(),
+            i64
+        ),
+        ("pull_request_mask_bits", gossip.pull.mask_bits(), i64),
         (
             "gossip_pull_request_no_budget",
             stats.gossip_pull_request_no_budget.clear(),
@@ -418,6 +427,21 @@ pub(crate) fn submit_gossip_stats(
     );
     datapoint_info!(
         "cluster_info_stats4",
+        (
+            "bad_contact_info_pull_request",
+            stats.bad_contact_info_pull_request.clear(),
+            i64
+        ),
+        (
+            "bad_contact_info_pull_response",
+            stats.bad_contact_info_pull_response.clear(),
+            i64
+        ),
+        (
+            "bad_contact_info_push_message",
+            stats.bad_contact_info_push_message.clear(),
+            i64
+        ),
         (
             "skip_push_message_shred_version",
             stats.skip_push_message_shred_version.clear(),
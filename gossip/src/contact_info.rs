@@ -42,6 +42,8 @@ const SOCKET_CACHE_SIZE: usize = SOCKET_TAG_TVU_QUIC as usize + 1usize;
 
 #[derive(Debug, Error)]
 pub enum Error {
+    #[error("Broadcast IP address: {0}")]
+    BroadcastIpAddr(IpAddr),
     #[error("Duplicate IP address: {0}")]
     DuplicateIpAddr(IpAddr),
     #[error("Duplicate socket: {0}")]
@@ -366,6 +368,12 @@ impl ContactInfo {
         LegacyContactInfo::is_valid_address(addr, socket_addr_space)
     }
 
+    // Returns an error if any of the advertised sockets has an address that
+    // can never reach the node.
+    pub(crate) fn sanitize_addrs(&self) -> Result<(), Error> {
+        self.addrs.iter().try_for_each(sanitize_advertised_addr)
+    }
+
     // Only for tests and simulations.
     pub fn new_localhost(pubkey: &Pubkey, wallclock: u64) -> Self {
         let mut node = Self::new(*pubkey, wallclock, /*shred_version:*/ 0u16);
@@ -481,8 +489,18 @@ pub(crate) fn sanitize_socket(socket: &SocketAddr) -> Result<(), Error> {
     if addr.is_unspecified() {
         return Err(Error::UnspecifiedIpAddr(addr));
     }
+    sanitize_advertised_addr(&addr)
+}
+
+// Nodes advertise the unspecified address for sockets they do not serve, but
+// a multicast or broadcast address never reaches the node and only pollutes
+// peer selection.
+pub(crate) fn sanitize_advertised_addr(addr: &IpAddr) -> Result<(), Error> {
     if addr.is_multicast() {
-        return Err(Error::MulticastIpAddr(addr));
+        return Err(Error::MulticastIpAddr(*addr));
+    }
+    if matches!(addr, IpAddr::V4(addr) if addr.is_broadcast()) {
+        return Err(Error::BroadcastIpAddr(*addr));
     }
     Ok(())
 }
@@ -699,6 +717,53 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_sanitize_socket() {
+        assert_matches!(
+            sanitize_socket(&SocketAddr::from(([1, 2, 3, 4], 8000))),
+            Ok(())
+        );
+        assert_matches!(
+            sanitize_socket(&SocketAddr::from(([1, 2, 3, 4], 0))),
+            Err(Error::InvalidPort(0))
+        );
+        assert_matches!(
+            sanitize_socket(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, 8000))),
+            Err(Error::UnspecifiedIpAddr(_))
+        );
+        assert_matches!(
+            sanitize_socket(&SocketAddr::from(([224, 0, 0, 1], 8000))),
+            Err(Error::MulticastIpAddr(_))
+        );
+        assert_matches!(
+            sanitize_socket(&SocketAddr::from((Ipv4Addr::BROADCAST, 8000))),
+            Err(Error::BroadcastIpAddr(_))
+        );
+        let mut node = ContactInfo::new(
+            Pubkey::new_unique(),
+            /*wallclock:*/ 0,
+            /*shred_version:*/ 0,
+        );
+        assert_matches!(
+            node.set_gossip((Ipv4Addr::BROADCAST, 8000)),
+            Err(Error::BroadcastIpAddr(_))
+        );
+        assert!(node.gossip().is_err());
+    }
+
+    #[test]
+    fn test_sanitize_addrs() {
+        let mut node = ContactInfo::new_localhost(&Pubkey::new_unique(), /*wallclock:*/ 0);
+        assert_matches!(node.sanitize_addrs(), Ok(()));
+        node.addrs.push(IpAddr::V4(Ipv4Addr::UNSPECIFIED));
+        assert_matches!(node.sanitize_addrs(), Ok(()));
+        node.addrs.push(IpAddr::V4(Ipv4Addr::BROADCAST));
+        assert_matches!(node.sanitize_addrs(), Err(Error::BroadcastIpAddr(_)));
+        node.addrs.pop();
+        node.addrs.push(IpAddr::from([224, 0, 0, 1]));
+        assert_matches!(node.sanitize_addrs(), Err(Error::MulticastIpAddr(_)));
+    }
+
     #[test]
     fn test_round_trip() {
         const KEYS: Range<u8> = 0u8..16u8;
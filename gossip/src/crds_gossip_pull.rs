@@ -43,7 +43,7 @@ use {
         net::SocketAddr,
         ops::Index,
         sync::{
-            atomic::{AtomicI64, AtomicUsize, Ordering},
+            atomic::{AtomicI64, AtomicU32, AtomicUsize, Ordering},
             Mutex, RwLock,
         },
         time::Duration,
@@ -55,6 +55,17 @@ pub const CRDS_GOSSIP_PULL_CRDS_TIMEOUT_MS: u64 = 15000;
 const FAILED_INSERTS_RETENTION_MS: u64 = 20_000;
 pub const FALSE_RATE: f64 = 0.1f64;
 pub const KEYS: f64 = 8f64;
+// Interval between consecutive rounds of pull requests. It starts at, and
+// settles back to, the default while pull responses only trickle in new
+// values. It shrinks towards the lower bound while a round inserts a
+// significant share of the crds table, i.e. while catching up with the
+// cluster, and backs off towards the upper bound once a round inserts nothing
+// new.
+pub(crate) const MIN_PULL_REQUEST_INTERVAL_MS: u64 = 100;
+pub(crate) const DEFAULT_PULL_REQUEST_INTERVAL_MS: u64 = 200;
+pub(crate) const MAX_PULL_REQUEST_INTERVAL_MS: u64 = 1_600;
+// A round is catching up if it inserts at least 1/64 of the crds table.
+const CATCH_UP_CRDS_SHARE: usize = 64;
 
 #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, AbiExample)]
 pub struct CrdsFilter {
@@ -203,6 +214,26 @@ pub struct ProcessPullStats {
     pub timeout_count: usize,
 }
 
+// Tracks when the next round of pull requests is due.
+struct PullRequestSchedule {
+    // Timestamp (ms) of the last round of pull requests.
+    last_request: u64,
+    // Interval (ms) until the next round of pull requests.
+    interval: u64,
+    // Value of CrdsGossipPull::num_pulls at the last round of pull requests.
+    num_pulls: usize,
+}
+
+impl Default for PullRequestSchedule {
+    fn default() -> Self {
+        Self {
+            last_request: 0,
+            interval: DEFAULT_PULL_REQUEST_INTERVAL_MS,
+            num_pulls: 0,
+        }
+    }
+}
+
 pub struct CrdsGossipPull {
     // Hash value and record time (ms) of the pull responses which failed to be
     // inserted in crds table; Preserved to stop the sender to send back the
@@ -211,6 +242,9 @@ pub struct CrdsGossipPull {
     failed_inserts: RwLock<VecDeque<(Hash, /*timestamp:*/ u64)>>,
     pub crds_timeout: u64,
     pub num_pulls: AtomicUsize,
+    schedule: Mutex<PullRequestSchedule>,
+    // Number of mask bits used by the most recent set of pull-request filters.
+    mask_bits: AtomicU32,
 }
 
 impl Default for CrdsGossipPull {
@@ -219,10 +253,54 @@ impl Default for CrdsGossipPull {
             failed_inserts: RwLock::default(),
             crds_timeout: CRDS_GOSSIP_PULL_CRDS_TIMEOUT_MS,
             num_pulls: AtomicUsize::default(),
+            schedule: Mutex::default(),
+            mask_bits: AtomicU32::default(),
         }
     }
 }
 impl CrdsGossipPull {
+    /// Returns true if a new round of pull requests is due. Each time a round
+    /// is due, the interval until the next one is adjusted by the number of
+    /// values pull responses inserted since the previous round, relative to
+    /// `crds_len`, the size of the crds table: halved while catching up,
+    /// moved back towards DEFAULT_PULL_REQUEST_INTERVAL_MS for fewer new
+    /// values, and doubled for none, within [MIN_PULL_REQUEST_INTERVAL_MS,
+    /// MAX_PULL_REQUEST_INTERVAL_MS].
+    pub(crate) fn should_generate_pull_requests(&self, now: u64, crds_len: usize) -> bool {
+        let mut schedule = self.schedule.lock().unwrap();
+        if now < schedule.last_request.saturating_add(schedule.interval) {
+            return false;
+        }
+        let num_pulls = self.num_pulls.load(Ordering::Relaxed);
+        if schedule.last_request != 0 {
+            let num_new_values = num_pulls.saturating_sub(schedule.num_pulls);
+            schedule.interval = if num_new_values == 0 {
+                schedule.interval.saturating_mul(2)
+            } else if num_new_values >= (crds_len / CATCH_UP_CRDS_SHARE).max(1) {
+                schedule.interval / 2
+            } else if schedule.interval < DEFAULT_PULL_REQUEST_INTERVAL_MS {
+                schedule.interval.saturating_mul(2)
+            } else {
+                (schedule.interval / 2).max(DEFAULT_PULL_REQUEST_INTERVAL_MS)
+            }
+            .clamp(MIN_PULL_REQUEST_INTERVAL_MS, MAX_PULL_REQUEST_INTERVAL_MS);
+        }
+        schedule.last_request = now;
+        schedule.num_pulls = num_pulls;
+        true
+    }
+
+    /// Current interval (ms) between consecutive rounds of pull requests.
+    pub(crate) fn pull_request_interval(&self) -> u64 {
+        self.schedule.lock().unwrap().interval
+    }
+
+    /// Number of mask bits, i.e. log2 of the number of partitions of the crds
+    /// table, used by the most recent set of pull-request filters.
+    pub(crate) fn mask_bits(&self) -> u32 {
+        self.mask_bits.load(Ordering::Relaxed)
+    }
+
     /// Generate a random request
     #[allow(clippy::too_many_arguments)]
     pub(crate) fn new_pull_request(
@@ -428,6 +506,7 @@ impl CrdsGossipPull {
         let num_items = crds.len() + crds.num_purged() + failed_inserts.len();
         let num_items = MIN_NUM_BLOOM_ITEMS.max(num_items);
         let filters = CrdsFilterSet::new(&mut rand::thread_rng(), num_items, bloom_size);
+        self.mask_bits.store(filters.mask_bits, Ordering::Relaxed);
         thread_pool.install(|| {
             crds.par_values()
                 .with_min_len(PAR_MIN_LENGTH)
@@ -754,6 +833,7 @@ pub(crate) mod tests {
         assert!(num_inserts > 30_000, "num inserts: {num_inserts}");
         let filters = crds_gossip_pull.build_crds_filters(&thread_pool, &crds, MAX_BLOOM_SIZE);
         assert_eq!(filters.len(), MIN_NUM_BLOOM_FILTERS.max(4));
+        assert_eq!(crds_gossip_pull.mask_bits(), filters[0].mask_bits);
         let crds = crds.read().unwrap();
         let purged: Vec<_> = thread_pool.install(|| crds.purged().collect());
         let hash_values: Vec<_> = crds.values().map(|v| v.value_hash).chain(purged).collect();
@@ -784,6 +864,61 @@ pub(crate) mod tests {
         assert!(false_positives < 20_000, "fp: {false_positives}");
     }
 
+    #[test]
+    fn test_should_generate_pull_requests() {
+        const CRDS_LEN: usize = 6_400;
+        let node = CrdsGossipPull::default();
+        let mut now = 1_000;
+        // The first round is always due and does not adjust the interval.
+        assert!(node.should_generate_pull_requests(now, CRDS_LEN));
+        assert!(!node.should_generate_pull_requests(now, CRDS_LEN));
+        assert_eq!(
+            node.pull_request_interval(),
+            DEFAULT_PULL_REQUEST_INTERVAL_MS
+        );
+        // No new values inserted; back off until the upper bound.
+        for interval in [400, 800, 1_600, 1_600] {
+            now += node.pull_request_interval();
+            assert!(!node.should_generate_pull_requests(now - 1, CRDS_LEN));
+            assert!(node.should_generate_pull_requests(now, CRDS_LEN));
+            assert_eq!(node.pull_request_interval(), interval);
+        }
+        // Catching up with the cluster; speed up to the lower bound.
+        for interval in [800, 400, 200, 100, 100] {
+            node.num_pulls.fetch_add(CRDS_LEN / 64, Ordering::Relaxed);
+            now += node.pull_request_interval();
+            assert!(node.should_generate_pull_requests(now, CRDS_LEN));
+            assert_eq!(node.pull_request_interval(), interval);
+        }
+        // Caught up; settle back to the default.
+        for interval in [200, 200] {
+            node.num_pulls.fetch_add(1, Ordering::Relaxed);
+            now += node.pull_request_interval();
+            assert!(node.should_generate_pull_requests(now, CRDS_LEN));
+            assert_eq!(node.pull_request_interval(), interval);
+        }
+    }
+
+    #[test]
+    fn test_should_generate_pull_requests_trickle() {
+        const CRDS_LEN: usize = 6_400;
+        let node = CrdsGossipPull::default();
+        let mut now = 1_000;
+        assert!(node.should_generate_pull_requests(now, CRDS_LEN));
+        // Votes and contact infos updated every round do not speed up pull
+        // requests past the default cadence.
+        for _ in 0..16 {
+            node.num_pulls
+                .fetch_add(CRDS_LEN / 64 - 1, Ordering::Relaxed);
+            now += node.pull_request_interval();
+            assert!(node.should_generate_pull_requests(now, CRDS_LEN));
+            assert_eq!(
+                node.pull_request_interval(),
+                DEFAULT_PULL_REQUEST_INTERVAL_MS
+            );
+        }
+    }
+
     #[test]
     fn test_new_pull_request() {
         let thread_pool = ThreadPoolBuilder::new().build().unwrap();
@@ -1,8 +1,8 @@
 use {
     crate::{
         contact_info::{
-            get_quic_socket, sanitize_quic_offset, sanitize_socket, ContactInfo, Error, Protocol,
-            SOCKET_ADDR_UNSPECIFIED,
+            get_quic_socket, sanitize_advertised_addr, sanitize_quic_offset, sanitize_socket,
+            ContactInfo, Error, Protocol, SOCKET_ADDR_UNSPECIFIED,
         },
         crds_value::MAX_WALLCLOCK,
     },
@@ -229,6 +229,25 @@ impl LegacyContactInfo {
     pub fn is_valid_address(addr: &SocketAddr, socket_addr_space: &SocketAddrSpace) -> bool {
         addr.port() != 0u16 && Self::is_valid_ip(addr.ip()) && socket_addr_space.check(addr)
     }
+
+    // Returns an error if any of the advertised sockets has an address that
+    // can never reach the node.
+    pub(crate) fn sanitize_addrs(&self) -> Result<(), Error> {
+        [
+            &self.gossip,
+            &self.tvu,
+            &self.tvu_quic,
+            &self.serve_repair_quic,
+            &self.tpu,
+            &self.tpu_forwards,
+            &self.tpu_vote,
+            &self.rpc,
+            &self.rpc_pubsub,
+            &self.serve_repair,
+        ]
+        .into_iter()
+        .try_for_each(|socket| sanitize_advertised_addr(&socket.ip()))
+    }
 }
 
 impl TryFrom<&ContactInfo> for LegacyContactInfo {
@@ -327,6 +346,24 @@ mod tests {
         assert!(ci.serve_repair.ip().is_unspecified());
     }
 
+    #[test]
+    fn test_sanitize_addrs() {
+        let mut ci = LegacyContactInfo::new_localhost(&Pubkey::new_unique(), 0);
+        assert!(ci.sanitize_addrs().is_ok());
+        // unset sockets are advertised with the unspecified address
+        assert!(LegacyContactInfo::default().sanitize_addrs().is_ok());
+        ci.rpc = socketaddr!(Ipv4Addr::BROADCAST, 1234);
+        assert!(matches!(
+            ci.sanitize_addrs(),
+            Err(Error::BroadcastIpAddr(_))
+        ));
+        ci.rpc = socketaddr!([224, 254, 0, 0], 1234);
+        assert!(matches!(
+            ci.sanitize_addrs(),
+            Err(Error::MulticastIpAddr(_))
+        ));
+    }
+
     #[test]
     fn test_sanitize() {
         let mut ci = LegacyContactInfo::default();
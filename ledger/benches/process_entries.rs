@@ -0,0 +1,107 @@
+#![allow(clippy::arithmetic_side_effects)]
+
+use {
+    criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput},
+    solana_entry::entry::{next_entry_mut, Entry},
+    solana_ledger::{
+        blockstore_processor::process_entries_for_tests,
+        genesis_utils::{create_genesis_config, GenesisConfigInfo},
+    },
+    solana_runtime::{bank::Bank, installed_scheduler_pool::BankWithScheduler},
+    solana_sdk::{
+        account::Account, pubkey::Pubkey, signature::Keypair, signer::Signer, stake_history::Epoch,
+        system_program, system_transaction,
+    },
+    std::sync::Arc,
+};
+
+const NUM_TRANSACTIONS: usize = 256;
+
+const CONFLICTING_PERCENTS: [usize; 3] = [0, 50, 100];
+
+fn setup() -> Arc<Bank> {
+    let GenesisConfigInfo {
+        mut genesis_config, ..
+    } = create_genesis_config(u64::MAX);
+
+    // Set a high ticks_per_slot so we don't run out of ticks
+    // during the benchmark
+    genesis_config.ticks_per_slot = 10_000;
+
+    let mut bank = Bank::new_for_benches(&genesis_config);
+
+    // Allow arbitrary transaction processing time for the purposes of this bench
+    bank.ns_per_slot = u128::MAX;
+
+    // set cost tracker limits to MAX so it will not filter out TXs
+    bank.write_cost_tracker()
+        .unwrap()
+        .set_limits(u64::MAX, u64::MAX, u64::MAX);
+    bank.wrap_with_bank_forks_for_tests().0
+}
+
+fn fund_account(bank: &Bank, pubkey: &Pubkey) {
+    bank.store_account(
+        pubkey,
+        &Account {
+            lamports: 5100,
+            data: vec![],
+            owner: system_program::id(),
+            executable: false,
+            rent_epoch: Epoch::MAX,
+        },
+    );
+}
+
+fn create_entries(bank: &Bank, conflicting_percent: usize) -> Vec<Entry> {
+    // Conflicting transfers all credit this account, so they contend for its write lock
+    let hot_account = Pubkey::new_unique();
+    fund_account(bank, &hot_account);
+    let mut hash = bank.last_blockhash();
+    (0..NUM_TRANSACTIONS)
+        .map(|i| {
+            let from = Keypair::new();
+            fund_account(bank, &from.pubkey());
+            // Spread the conflicting transfers evenly among the others
+            let is_conflicting =
+                (i + 1) * conflicting_percent / 100 > i * conflicting_percent / 100;
+            let to = if is_conflicting {
+                hot_account
+            } else {
+                let to = Pubkey::new_unique();
+                fund_account(bank, &to);
+                to
+            };
+            let tx = system_transaction::transfer(&from, &to, 1, bank.last_blockhash());
+            // One transaction per entry, so that process_entries decides how to batch them
+            next_entry_mut(&mut hash, 0, vec![tx])
+        })
+        .collect()
+}
+
+fn bench_process_entries(c: &mut Criterion) {
+    let mut group = c.benchmark_group("process_entries");
+    group.throughput(Throughput::Elements(NUM_TRANSACTIONS as u64));
+    for conflicting_percent in CONFLICTING_PERCENTS {
+        let bank = setup();
+        let entries = create_entries(&bank, conflicting_percent);
+
+        // Each iteration replays the entries on a new child of `bank`. Siblings do not see
+        // each other's transactions, so the same transactions can be processed again.
+        let mut slot = bank.slot();
+        group.bench_function(
+            BenchmarkId::new("conflicting_percent", conflicting_percent),
+            |b| {
+                b.iter(|| {
+                    slot += 1;
+                    let child = Bank::new_from_parent(bank.clone(), &Pubkey::default(), slot);
+                    let child = BankWithScheduler::new_without_scheduler(Arc::new(child));
+                    process_entries_for_tests(&child, entries.clone(), None, None).unwrap();
+                });
+            },
+        );
+    }
+}
+
+criterion_group!(benches, bench_process_entries);
+criterion_main!(benches);
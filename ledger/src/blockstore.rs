@@ -2379,6 +2379,7 @@ impl Blockstore {
         if highest_primary_index_slot.is_some_and(|slot| slot != 0) {
             self.set_highest_primary_index_slot(highest_primary_index_slot);
         } else {
+            self.set_highest_primary_index_slot(None);
             self.db.set_clean_slot_0(true);
         }
         Ok(())
@@ -3574,6 +3575,31 @@ impl Blockstore {
         self.db.is_primary_access()
     }
 
+    /// Makes writes done by the primary instance since this blockstore was
+    /// opened, or since the last call, visible to it.
+    ///
+    /// Only meaningful for a blockstore opened with
+    /// [`AccessType::Secondary`], which otherwise only sees the state of the
+    /// ledger at the time it was opened. Reads may run concurrently with this
+    /// call; they observe either the old or the new state.
+    ///
+    /// The max root, the highest primary transaction status index slot and
+    /// the lowest cleanup slot are reloaded afterwards, so that slots rooted
+    /// or purged by the primary are reported as such. Slots older than the
+    /// oldest remaining slot meta are treated as cleaned up.
+    pub fn try_catch_up_with_primary(&self) -> Result<()> {
+        self.db.try_catch_up_with_primary()?;
+        if let Some((max_root, _)) = self.db.iter::<cf::Root>(IteratorMode::End)?.next() {
+            self.max_root.fetch_max(max_root, Ordering::Relaxed);
+        }
+        self.update_highest_primary_index_slot()?;
+        if let Some((lowest_slot, _)) = self.meta_cf.iter(IteratorMode::Start)?.next() {
+            let mut lowest_cleanup_slot = self.lowest_cleanup_slot.write().unwrap();
+            *lowest_cleanup_slot = (*lowest_cleanup_slot).max(lowest_slot.saturating_sub(1));
+        }
+        Ok(())
+    }
+
     /// Scan for any ancestors of the supplied `start_root` that are not
     /// marked as roots themselves. Mark any found slots as roots since
     /// the ancestor of a root is also inherently a root. Returns the
@@ -7393,6 +7419,43 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_secondary_try_catch_up_with_primary() {
+        let ledger_path = get_tmp_ledger_path_auto_delete!();
+        let primary = Blockstore::open(ledger_path.path()).unwrap();
+        let (shreds, _) = make_slot_entries(1, 0, 4, /*merkle_variant:*/ true);
+        primary.insert_shreds(shreds, None, false).unwrap();
+
+        let secondary = Blockstore::open_with_options(
+            ledger_path.path(),
+            BlockstoreOptions {
+                access_type: AccessType::Secondary,
+                ..BlockstoreOptions::default()
+            },
+        )
+        .unwrap();
+        assert!(!secondary.is_primary_access());
+        assert!(secondary.meta(1).unwrap().unwrap().is_full());
+
+        // Writes made after the secondary was opened are not visible until
+        // it catches up.
+        let (shreds, _) = make_slot_entries(2, 1, 4, /*merkle_variant:*/ true);
+        primary.insert_shreds(shreds, None, false).unwrap();
+        assert!(secondary.meta(2).unwrap().is_none());
+        secondary.try_catch_up_with_primary().unwrap();
+        assert!(secondary.meta(2).unwrap().unwrap().is_full());
+        assert_eq!(secondary.meta(1).unwrap().unwrap().next_slots, vec![2]);
+
+        // Roots and purges done by the primary are picked up as well.
+        primary.set_roots([1, 2].iter()).unwrap();
+        primary.purge_slots(0, 1, PurgeType::Exact);
+        assert_eq!(secondary.max_root(), 0);
+        secondary.try_catch_up_with_primary().unwrap();
+        assert_eq!(secondary.max_root(), 2);
+        assert_eq!(*secondary.lowest_cleanup_slot.read().unwrap(), 1);
+        assert!(secondary.meta(1).unwrap().is_none());
+    }
+
     #[test]
     fn test_insert_multiple_is_last() {
         solana_logger::setup();
@@ -762,6 +762,13 @@ impl Rocks {
             Err(e) => Err(BlockstoreError::RocksDb(e)),
         }
     }
+
+    fn try_catch_up_with_primary(&self) -> Result<()> {
+        match self.db.try_catch_up_with_primary() {
+            Ok(_) => Ok(()),
+            Err(e) => Err(BlockstoreError::RocksDb(e)),
+        }
+    }
 }
 
 pub trait Column {
@@ -1541,6 +1548,10 @@ impl Database {
         self.backend.live_files_metadata()
     }
 
+    pub fn try_catch_up_with_primary(&self) -> Result<()> {
+        self.backend.try_catch_up_with_primary()
+    }
+
     pub fn compact_range_cf<C: Column + ColumnName>(&self, from: &[u8], to: &[u8]) {
         let cf = self.cf_handle::<C>();
         self.backend.db.compact_range_cf(cf, Some(from), Some(to));
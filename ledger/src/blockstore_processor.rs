@@ -1974,10 +1974,12 @@ pub mod tests {
         assert_matches::assert_matches,
         rand::{thread_rng, Rng},
         solana_entry::entry::{create_ticks, next_entry, next_entry_mut},
+        solana_perf::test_tx::{test_conflicting_txs, test_keypairs, test_seed},
         solana_program_runtime::declare_process_instruction,
         solana_runtime::{
             genesis_utils::{
-                self, create_genesis_config_with_vote_accounts, ValidatorVoteKeypairs,
+                self, create_genesis_config_with_funded_accounts,
+                create_genesis_config_with_vote_accounts, ValidatorVoteKeypairs,
             },
             installed_scheduler_pool::{
                 MockInstalledScheduler, MockUninstalledScheduler, SchedulingContext,
@@ -3360,27 +3362,31 @@ pub mod tests {
 
     #[test]
     fn test_process_entry_tx_random_execution_with_error() {
-        let GenesisConfigInfo {
-            genesis_config,
-            mint_keypair,
-            ..
-        } = create_genesis_config(1_000_000_000);
-        let bank = Bank::new_with_bank_forks_for_tests(&genesis_config).0;
-
+        solana_logger::setup();
         const NUM_TRANSFERS_PER_ENTRY: usize = 8;
         const NUM_TRANSFERS: usize = NUM_TRANSFERS_PER_ENTRY * 32;
         // large enough to scramble locks and results
 
-        let keypairs: Vec<_> = (0..NUM_TRANSFERS * 2).map(|_| Keypair::new()).collect();
-
-        // give everybody one lamport
-        for keypair in &keypairs {
-            bank.transfer(1, &mint_keypair, &keypair.pubkey())
-                .expect("funding failed");
-        }
+        // give everybody one lamport, and the payer of the failing
+        // transactions enough to pay for them
+        let seed = test_seed();
+        let mut balances = vec![1; NUM_TRANSFERS * 2];
+        balances.push(1_000_000);
+        let (GenesisConfigInfo { genesis_config, .. }, keypairs) =
+            create_genesis_config_with_funded_accounts(1_000_000_000, seed, &balances);
+        let payer = &keypairs[NUM_TRANSFERS * 2];
+        let bank = Bank::new_with_bank_forks_for_tests(&genesis_config).0;
         let mut hash = bank.last_blockhash();
 
-        let present_account_key = Keypair::new();
+        // the keypairs following the funded ones come from the same seed
+        let mut unfunded_keypairs = test_keypairs(
+            seed,
+            balances.len() + 1 + NUM_TRANSFERS / NUM_TRANSFERS_PER_ENTRY,
+        )
+        .split_off(balances.len());
+        let present_account_key = unfunded_keypairs.remove(0);
+        // a distinct owner for each entry keeps the failing transactions unique
+        let owners = unfunded_keypairs;
         let present_account = AccountSharedData::new(1, 10, &Pubkey::default());
         bank.store_account(&present_account_key.pubkey(), &present_account);
 
@@ -3399,12 +3405,12 @@ pub mod tests {
                     .collect::<Vec<_>>();
 
                 transactions.push(system_transaction::create_account(
-                    &mint_keypair,
+                    payer,
                     &present_account_key, // puts a TX error in results
                     bank.last_blockhash(),
                     1,
                     0,
-                    &solana_sdk::pubkey::new_rand(),
+                    &owners[i / NUM_TRANSFERS_PER_ENTRY].pubkey(),
                 ));
 
                 next_entry_mut(&mut hash, 0, transactions)
@@ -3416,6 +3422,52 @@ pub mod tests {
         );
     }
 
+    #[test]
+    fn test_process_entries_conflicting_txs_matches_serial() {
+        solana_logger::setup();
+        let seed = test_seed();
+        let (GenesisConfigInfo { genesis_config, .. }, funded_keypairs) =
+            create_genesis_config_with_funded_accounts(1_000_000_000, seed, &[1_000_000; 8]);
+        let bank = Bank::new_with_bank_forks_for_tests(&genesis_config).0;
+        let serial_bank = Bank::new_with_bank_forks_for_tests(&genesis_config).0;
+        assert_eq!(bank.last_blockhash(), serial_bank.last_blockhash());
+
+        let (keypairs, mut transactions) =
+            test_conflicting_txs(seed, 8, 256, bank.last_blockhash());
+        assert_eq!(
+            keypairs.iter().map(Keypair::pubkey).collect::<Vec<_>>(),
+            funded_keypairs
+                .iter()
+                .map(Keypair::pubkey)
+                .collect::<Vec<_>>()
+        );
+        // Identical transfers would be rejected as already processed.
+        let mut signatures = HashSet::new();
+        transactions.retain(|tx| signatures.insert(tx.signatures[0]));
+
+        // One transaction per entry, so that process_entries is free to batch
+        // non-conflicting neighbours and has to serialize the rest.
+        let mut hash = bank.last_blockhash();
+        let entries: Vec<_> = transactions
+            .iter()
+            .map(|tx| next_entry_mut(&mut hash, 0, vec![tx.clone()]))
+            .collect();
+        assert_eq!(
+            process_entries_for_tests_without_scheduler(&bank, entries),
+            Ok(())
+        );
+        for tx in &transactions {
+            assert_eq!(serial_bank.process_transaction(tx), Ok(()));
+        }
+
+        for keypair in &keypairs {
+            assert_eq!(
+                bank.get_balance(&keypair.pubkey()),
+                serial_bank.get_balance(&keypair.pubkey()),
+            );
+        }
+    }
+
     #[test]
     fn test_process_entry_tx_random_execution_no_error() {
         // entropy multiplier should be big enough to provide sufficient entropy
@@ -3790,23 +3842,23 @@ pub mod tests {
         // this test throws lots of rayon threads at process_entries()
         //  finds bugs in very low-layer stuff
         solana_logger::setup();
-        let GenesisConfigInfo {
-            genesis_config,
-            mint_keypair,
-            ..
-        } = create_genesis_config(1_000_000_000);
-        let mut bank = Arc::new(Bank::new_for_tests(&genesis_config));
-
         const NUM_TRANSFERS_PER_ENTRY: usize = 8;
         const NUM_TRANSFERS: usize = NUM_TRANSFERS_PER_ENTRY * 32;
 
-        let keypairs: Vec<_> = (0..NUM_TRANSFERS * 2).map(|_| Keypair::new()).collect();
-
         // give everybody one lamport
-        for keypair in &keypairs {
-            bank.transfer(1, &mint_keypair, &keypair.pubkey())
-                .expect("funding failed");
-        }
+        let (
+            GenesisConfigInfo {
+                genesis_config,
+                mint_keypair,
+                ..
+            },
+            keypairs,
+        ) = create_genesis_config_with_funded_accounts(
+            1_000_000_000,
+            test_seed(),
+            &[1; NUM_TRANSFERS * 2],
+        );
+        let mut bank = Arc::new(Bank::new_for_tests(&genesis_config));
 
         let present_account_key = Keypair::new();
         let present_account = AccountSharedData::new(1, 10, &Pubkey::default());
@@ -127,10 +127,11 @@ pub fn verify_balances<S: ::std::hash::BuildHasher>(
         .unwrap();
     let client = ThinClient::new(rpc, tpu, connection_cache);
     for (pk, b) in expected_balances {
-        let bal = client
-            .poll_get_balance_with_commitment(&pk, CommitmentConfig::processed())
-            .expect("balance in source");
-        assert_eq!(bal, b);
+        assert_eq!(
+            client.wait_for_balance_with_commitment(&pk, Some(b), CommitmentConfig::processed()),
+            Some(b),
+            "balance of {pk}"
+        );
     }
 }
 
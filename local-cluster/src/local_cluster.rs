@@ -176,7 +176,10 @@ impl LocalCluster {
                 .unwrap()
                 .0,
         ];
-        config.tower_storage = Arc::new(FileTowerStorage::new(ledger_path.to_path_buf()));
+        // Fenced storage is shared between nodes on purpose, keep it.
+        if !config.tower_storage.is_fenced() {
+            config.tower_storage = Arc::new(FileTowerStorage::new(ledger_path.to_path_buf()));
+        }
 
         let snapshot_config = &mut config.snapshot_config;
         let dummy: PathBuf = DUMMY_SNAPSHOT_CONFIG_PATH_MARKER.into();
@@ -451,12 +454,7 @@ impl LocalCluster {
         mut voting_keypair: Option<Arc<Keypair>>,
         socket_addr_space: SocketAddrSpace,
     ) -> Pubkey {
-        let (rpc, tpu) = LegacyContactInfo::try_from(&self.entry_point_info)
-            .map(|node| {
-                cluster_tests::get_client_facing_addr(self.connection_cache.protocol(), node)
-            })
-            .unwrap();
-        let client = ThinClient::new(rpc, tpu, self.connection_cache.clone());
+        let client = self.build_thin_client(&self.entry_point_info);
 
         // Must have enough tokens to fund vote account and set delegate
         let should_create_vote_pubkey = voting_keypair.is_none();
@@ -548,12 +546,7 @@ impl LocalCluster {
     }
 
     pub fn transfer(&self, source_keypair: &Keypair, dest_pubkey: &Pubkey, lamports: u64) -> u64 {
-        let (rpc, tpu) = LegacyContactInfo::try_from(&self.entry_point_info)
-            .map(|node| {
-                cluster_tests::get_client_facing_addr(self.connection_cache.protocol(), node)
-            })
-            .unwrap();
-        let client = ThinClient::new(rpc, tpu, self.connection_cache.clone());
+        let client = self.build_thin_client(&self.entry_point_info);
         Self::transfer_with_client(&client, source_keypair, dest_pubkey, lamports)
     }
 
@@ -808,6 +801,22 @@ impl LocalCluster {
         }
     }
 
+    /// Builds a client for `contact_info` that streams account changes from
+    /// the node's pubsub endpoint, if it advertises one, while waiting for
+    /// balances.
+    fn build_thin_client(&self, contact_info: &ContactInfo) -> ThinClient {
+        let (rpc, tpu) = LegacyContactInfo::try_from(contact_info)
+            .map(|node| {
+                cluster_tests::get_client_facing_addr(self.connection_cache.protocol(), node)
+            })
+            .unwrap();
+        let mut client = ThinClient::new(rpc, tpu, self.connection_cache.clone());
+        if let Ok(rpc_pubsub) = contact_info.rpc_pubsub() {
+            client.set_pubsub_url(&format!("ws://{rpc_pubsub}/"));
+        }
+        client
+    }
+
     fn build_tpu_client<F>(&self, rpc_client_builder: F) -> Result<QuicTpuClient>
     where
         F: FnOnce(String) -> Arc<RpcClient>,
@@ -843,14 +852,9 @@ impl Cluster for LocalCluster {
     }
 
     fn get_validator_client(&self, pubkey: &Pubkey) -> Option<ThinClient> {
-        self.validators.get(pubkey).map(|f| {
-            let (rpc, tpu) = LegacyContactInfo::try_from(&f.info.contact_info)
-                .map(|node| {
-                    cluster_tests::get_client_facing_addr(self.connection_cache.protocol(), node)
-                })
-                .unwrap();
-            ThinClient::new(rpc, tpu, self.connection_cache.clone())
-        })
+        self.validators
+            .get(pubkey)
+            .map(|f| self.build_thin_client(&f.info.contact_info))
     }
 
     fn build_tpu_quic_client(&self) -> Result<QuicTpuClient> {
@@ -12,10 +12,12 @@ use {
     solana_client::thin_client::ThinClient,
     solana_core::{
         consensus::{
-            tower_storage::FileTowerStorage, Tower, SWITCH_FORK_THRESHOLD, VOTE_THRESHOLD_DEPTH,
+            tower_storage::{FileTowerStorage, SharedFileTowerStorage, TowerStorage},
+            Tower, SWITCH_FORK_THRESHOLD, VOTE_THRESHOLD_DEPTH,
         },
         optimistic_confirmation_verifier::OptimisticConfirmationVerifier,
         replay_stage::DUPLICATE_THRESHOLD,
+        standby_service::StandbyConfig,
         validator::{BlockProductionMethod, BlockVerificationMethod, ValidatorConfig},
     },
     solana_download_utils::download_snapshot_archive,
@@ -2500,6 +2502,91 @@ fn test_restart_tower_rollback() {
     );
 }
 
+#[test]
+#[serial]
+fn test_standby_takes_over_primary() {
+    // A standby shares the primary's tower storage and switches to the
+    // primary's identity once the primary stops voting.
+    solana_logger::setup_with_default(RUST_LOG_FILTER);
+
+    let node_stakes = vec![DEFAULT_NODE_STAKE * 100, DEFAULT_NODE_STAKE];
+    let validator_keys = node_stakes
+        .iter()
+        .map(|_| (Arc::new(Keypair::new()), true))
+        .collect::<Vec<_>>();
+    let primary_keypair = validator_keys[1].0.clone();
+    let primary_pubkey = primary_keypair.pubkey();
+
+    let tower_dir = tempfile::tempdir_in(farf_dir()).unwrap();
+    let shared_tower_storage = SharedFileTowerStorage::new(tower_dir.path().to_path_buf());
+    let last_vote_in_shared_tower = || {
+        shared_tower_storage
+            .peek(&primary_pubkey)
+            .ok()
+            .and_then(|tower| tower.last_voted_slot())
+    };
+    let mut validator_configs =
+        make_identical_validator_configs(&ValidatorConfig::default_for_test(), node_stakes.len());
+    validator_configs[1].tower_storage =
+        Arc::new(SharedFileTowerStorage::new(tower_dir.path().to_path_buf()));
+    validator_configs[1].check_tower_lease = true;
+
+    let mut config = ClusterConfig {
+        cluster_lamports: DEFAULT_CLUSTER_LAMPORTS + DEFAULT_NODE_STAKE * 100,
+        node_stakes: node_stakes.clone(),
+        validator_configs,
+        validator_keys: Some(validator_keys),
+        skip_warmup_slots: true,
+        ..ClusterConfig::default()
+    };
+    let mut cluster = LocalCluster::new(&mut config, SocketAddrSpace::Unspecified);
+
+    let primary_voting_keypair = cluster.validators[&primary_pubkey]
+        .info
+        .voting_keypair
+        .clone();
+    let mut standby_config = ValidatorConfig::default_for_test();
+    standby_config.tower_storage =
+        Arc::new(SharedFileTowerStorage::new(tower_dir.path().to_path_buf()));
+    standby_config.standby_config = Some(StandbyConfig {
+        primary_identity: primary_keypair,
+        max_missed_slots: 32,
+    });
+    cluster.add_validator(
+        &standby_config,
+        0,
+        Arc::new(Keypair::new()),
+        Some(primary_voting_keypair),
+        SocketAddrSpace::Unspecified,
+    );
+
+    // Wait for the primary to vote, then stop it
+    loop {
+        sleep(Duration::from_millis(1000));
+        if last_vote_in_shared_tower().unwrap_or(0) > 1 {
+            break;
+        }
+    }
+    cluster.exit_node(&primary_pubkey);
+    let primary_last_vote = last_vote_in_shared_tower().unwrap();
+    info!("primary exited, last vote {primary_last_vote}");
+
+    // The standby resumes voting from the primary's tower
+    let now = Instant::now();
+    loop {
+        let last_vote = last_vote_in_shared_tower().unwrap();
+        if last_vote > primary_last_vote {
+            info!("standby voted on {last_vote}");
+            break;
+        }
+        assert!(
+            now.elapsed() < Duration::from_secs(180),
+            "standby did not take over"
+        );
+        sleep(Duration::from_millis(1000));
+    }
+}
+
 #[test]
 #[serial]
 fn test_run_test_load_program_accounts_partition_root() {
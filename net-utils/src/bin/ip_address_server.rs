@@ -13,6 +13,12 @@ fn main() {
                 .required(true)
                 .help("TCP port to bind to"),
         )
+        .arg(
+            Arg::new("http_port")
+                .long("http-port")
+                .takes_value(true)
+                .help("Also serve /health and /public-ip as JSON over HTTP on this TCP port"),
+        )
         .get_matches();
 
     let port = matches.value_of("port").unwrap();
@@ -21,7 +27,20 @@ fn main() {
         .unwrap_or_else(|_| panic!("Unable to parse {port}"));
     let bind_addr = SocketAddr::from((Ipv4Addr::UNSPECIFIED, port));
     let tcp_listener = TcpListener::bind(bind_addr).expect("unable to start tcp listener");
-    let _runtime = solana_net_utils::ip_echo_server(tcp_listener, /*shred_version=*/ None);
+    let runtime = solana_net_utils::ip_echo_server(tcp_listener, /*shred_version=*/ None);
+    if let Some(http_port) = matches.value_of("http_port") {
+        let http_port = http_port
+            .parse()
+            .unwrap_or_else(|_| panic!("Unable to parse {http_port}"));
+        let http_bind_addr = SocketAddr::from((Ipv4Addr::UNSPECIFIED, http_port));
+        let http_listener =
+            TcpListener::bind(http_bind_addr).expect("unable to start http listener");
+        solana_net_utils::ip_echo_http_server(
+            &runtime,
+            http_listener,
+            /*shred_version=*/ None,
+        );
+    }
     loop {
         std::thread::park();
     }
@@ -2,6 +2,7 @@ use {
     crate::{HEADER_LENGTH, IP_ECHO_SERVER_RESPONSE_LENGTH},
     log::*,
     serde_derive::{Deserialize, Serialize},
+    serde_json::{json, Value},
     solana_sdk::deserialize_utils::default_on_eof,
     std::{
         io,
@@ -22,6 +23,8 @@ pub const MAX_PORT_COUNT_PER_MESSAGE: usize = 4;
 
 const IO_TIMEOUT: Duration = Duration::from_secs(5);
 
+const MAX_HTTP_REQUEST_HEADER_LENGTH: usize = 8192;
+
 #[derive(Serialize, Deserialize, Default, Debug)]
 pub(crate) struct IpEchoServerMessage {
     tcp_ports: [u16; MAX_PORT_COUNT_PER_MESSAGE], // Fixed size list of ports to avoid vec serde
@@ -164,6 +167,116 @@ async fn run_echo_server(tcp_listener: std::net::TcpListener, shred_version: Opt
     }
 }
 
+fn http_response(status: &str, body: Value) -> String {
+    let body = body.to_string();
+    format!(
+        "HTTP/1.1 {status}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+         Connection: close\r\n\r\n{body}",
+        body.len()
+    )
+}
+
+fn route_http_request(
+    path: Option<&str>,
+    peer_addr: SocketAddr,
+    shred_version: Option<u16>,
+) -> String {
+    match path {
+        Some("/health") => http_response("200 OK", json!({ "status": "ok" })),
+        Some("/public-ip") => http_response(
+            "200 OK",
+            json!({ "address": peer_addr.ip(), "shredVersion": shred_version }),
+        ),
+        _ => http_response("404 Not Found", json!({ "error": "not found" })),
+    }
+}
+
+async fn process_http_connection(
+    mut socket: TcpStream,
+    peer_addr: SocketAddr,
+    shred_version: Option<u16>,
+) -> io::Result<()> {
+    let mut data = vec![0u8; MAX_HTTP_REQUEST_HEADER_LENGTH];
+    let mut len = 0;
+    // Read up to the end of the header, so that no request data is left
+    // unread when the connection is closed.
+    while !data[..len].windows(4).any(|window| window == b"\r\n\r\n") {
+        if len == data.len() {
+            let response = http_response(
+                "431 Request Header Fields Too Large",
+                json!({ "error": "request header too large" }),
+            );
+            timeout(IO_TIMEOUT, socket.write_all(response.as_bytes())).await??;
+            return close_http_connection(socket).await;
+        }
+        let read = timeout(IO_TIMEOUT, socket.read(&mut data[len..])).await??;
+        if read == 0 {
+            break;
+        }
+        len += read;
+    }
+    let request = String::from_utf8_lossy(&data[..len]);
+    let mut request_line = request
+        .lines()
+        .next()
+        .unwrap_or_default()
+        .split_whitespace();
+    let path = match request_line.next() {
+        Some("GET") => request_line.next(),
+        _ => None,
+    };
+    let response = route_http_request(path, peer_addr, shred_version);
+    timeout(IO_TIMEOUT, socket.write_all(response.as_bytes())).await??;
+    close_http_connection(socket).await
+}
+
+// Closing a socket with unread data resets the connection, which may discard
+// the response before the client reads it. Half-close instead and drain
+// whatever the client still sends until it closes its side.
+async fn close_http_connection(mut socket: TcpStream) -> io::Result<()> {
+    socket.shutdown().await?;
+    let mut discard = [0u8; 1024];
+    timeout(IO_TIMEOUT, async {
+        while socket.read(&mut discard).await? > 0 {}
+        Ok::<_, io::Error>(())
+    })
+    .await?
+}
+
+async fn run_http_server(tcp_listener: std::net::TcpListener, shred_version: Option<u16>) {
+    info!("http bound to {:?}", tcp_listener.local_addr().unwrap());
+    let tcp_listener =
+        TcpListener::from_std(tcp_listener).expect("Failed to convert std::TcpListener");
+
+    loop {
+        match tcp_listener.accept().await {
+            Ok((socket, peer_addr)) => {
+                runtime::Handle::current().spawn(async move {
+                    if let Err(err) =
+                        process_http_connection(socket, peer_addr, shred_version).await
+                    {
+                        info!("http session failed: {:?}", err);
+                    }
+                });
+            }
+            Err(err) => warn!("http listener accept failed: {:?}", err),
+        }
+    }
+}
+
+/// Starts an HTTP listener on the echo server's runtime for monitoring systems
+/// that cannot speak the binary protocol. `GET /health` reports that the
+/// server is up and `GET /public-ip` reports, as JSON, the address the server
+/// observes for the caller along with the cluster shred-version.
+pub fn ip_echo_http_server(
+    ip_echo_server: &IpEchoServer,
+    tcp_listener: std::net::TcpListener,
+    shred_version: Option<u16>,
+) {
+    tcp_listener.set_nonblocking(true).unwrap();
+    ip_echo_server.spawn(run_http_server(tcp_listener, shred_version));
+}
+
 /// Starts a simple TCP server on the given port that echos the IP address of any peer that
 /// connects.  Used by |get_public_ip_addr|
 pub fn ip_echo_server(
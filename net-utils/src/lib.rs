@@ -16,8 +16,12 @@ use {
 };
 
 mod ip_echo_server;
-pub use ip_echo_server::{ip_echo_server, IpEchoServer, MAX_PORT_COUNT_PER_MESSAGE};
+pub use ip_echo_server::{
+    ip_echo_http_server, ip_echo_server, IpEchoServer, MAX_PORT_COUNT_PER_MESSAGE,
+};
 use ip_echo_server::{IpEchoServerMessage, IpEchoServerResponse};
+pub mod proxy;
+use proxy::ProxyConfig;
 
 /// A data type representing a public Udp socket
 pub struct UdpSocketPair {
@@ -37,9 +41,14 @@ pub(crate) const IP_ECHO_SERVER_RESPONSE_LENGTH: usize = HEADER_LENGTH + 23;
 fn ip_echo_server_request(
     ip_echo_server_addr: &SocketAddr,
     msg: IpEchoServerMessage,
+    proxy: Option<&ProxyConfig>,
 ) -> Result<IpEchoServerResponse, String> {
     let timeout = Duration::new(5, 0);
-    TcpStream::connect_timeout(ip_echo_server_addr, timeout)
+    let stream = match proxy {
+        Some(proxy) => proxy.connect_timeout(ip_echo_server_addr, timeout),
+        None => TcpStream::connect_timeout(ip_echo_server_addr, timeout),
+    };
+    stream
         .and_then(|mut stream| {
             // Start with HEADER_LENGTH null bytes to avoid looking like an HTTP GET/POST request
             let mut bytes = vec![0; HEADER_LENGTH];
@@ -101,12 +110,24 @@ fn ip_echo_server_request(
 /// Determine the public IP address of this machine by asking an ip_echo_server at the given
 /// address
 pub fn get_public_ip_addr(ip_echo_server_addr: &SocketAddr) -> Result<IpAddr, String> {
-    let resp = ip_echo_server_request(ip_echo_server_addr, IpEchoServerMessage::default())?;
+    let resp = ip_echo_server_request(ip_echo_server_addr, IpEchoServerMessage::default(), None)?;
     Ok(resp.address)
 }
 
 pub fn get_cluster_shred_version(ip_echo_server_addr: &SocketAddr) -> Result<u16, String> {
-    let resp = ip_echo_server_request(ip_echo_server_addr, IpEchoServerMessage::default())?;
+    get_cluster_shred_version_with_proxy(ip_echo_server_addr, None)
+}
+
+/// Like [`get_cluster_shred_version`], but connects through `proxy` if one is given.
+///
+/// Only the shred version can be fetched through a proxy: the public IP address and
+/// the port reachability checks are about the address the request comes from, so
+/// those requests always connect directly.
+pub fn get_cluster_shred_version_with_proxy(
+    ip_echo_server_addr: &SocketAddr,
+    proxy: Option<&ProxyConfig>,
+) -> Result<u16, String> {
+    let resp = ip_echo_server_request(ip_echo_server_addr, IpEchoServerMessage::default(), proxy)?;
     resp.shred_version
         .ok_or_else(|| String::from("IP echo server does not return a shred-version"))
 }
@@ -132,6 +153,7 @@ fn do_verify_reachable_ports(
     let _ = ip_echo_server_request(
         ip_echo_server_addr,
         IpEchoServerMessage::new(&tcp_ports, &[]),
+        None,
     )
     .map_err(|err| warn!("ip_echo_server request failed: {}", err));
 
@@ -213,6 +235,7 @@ fn do_verify_reachable_ports(
             let _ = ip_echo_server_request(
                 ip_echo_server_addr,
                 IpEchoServerMessage::new(&[], &checked_ports),
+                None,
             )
             .map_err(|err| warn!("ip_echo_server request failed: {}", err));
 
@@ -755,6 +778,62 @@ mod tests {
         assert!(verify_reachable_ports(&server_ip_echo_addr, vec![], &[],));
     }
 
+    #[test]
+    fn test_ip_echo_http_server() {
+        solana_logger::setup();
+        let ip_addr = IpAddr::V4(Ipv4Addr::UNSPECIFIED);
+        let (_server_port, (_server_udp_socket, server_tcp_listener)) =
+            bind_common_in_range(ip_addr, (3200, 3250)).unwrap();
+        let http_listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let http_addr = http_listener.local_addr().unwrap();
+
+        let runtime = ip_echo_server(server_tcp_listener, /*shred_version=*/ Some(42));
+        ip_echo_http_server(&runtime, http_listener, /*shred_version=*/ Some(42));
+
+        let get = |path: &str| {
+            let mut stream = TcpStream::connect(http_addr).unwrap();
+            stream
+                .write_all(format!("GET {path} HTTP/1.1\r\nHost: localhost\r\n\r\n").as_bytes())
+                .unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+        let response = get("/health");
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with(r#"{"status":"ok"}"#));
+        let response = get("/public-ip");
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with(r#"{"address":"127.0.0.1","shredVersion":42}"#));
+        assert!(get("/").starts_with("HTTP/1.1 404 Not Found"));
+
+        // A request with a full set of headers, sent in several writes, gets
+        // its whole response rather than a reset connection.
+        let mut stream = TcpStream::connect(http_addr).unwrap();
+        stream
+            .write_all(b"GET /public-ip HTTP/1.1\r\nHost: localhost\r\n")
+            .unwrap();
+        for i in 0..32 {
+            stream
+                .write_all(format!("X-Header-{i}: {}\r\n", "a".repeat(64)).as_bytes())
+                .unwrap();
+        }
+        stream
+            .write_all(b"User-Agent: monitor\r\nAccept: application/json\r\n\r\n")
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.ends_with(r#"{"address":"127.0.0.1","shredVersion":42}"#));
+
+        // Headers past the limit are refused.
+        let response = get(&format!(
+            "/health HTTP/1.1\r\nX-Padding: {}",
+            "a".repeat(8192)
+        ));
+        assert!(response.starts_with("HTTP/1.1 431 Request Header Fields Too Large"));
+    }
+
     #[test]
     fn test_get_public_ip_addr_reachable() {
         solana_logger::setup();
@@ -1,10 +1,11 @@
 use {
-    rand::{CryptoRng, Rng, RngCore},
+    rand::{thread_rng, CryptoRng, Rng, RngCore, SeedableRng},
+    rand_chacha::ChaChaRng,
     solana_sdk::{
         clock::Slot,
         hash::Hash,
         instruction::CompiledInstruction,
-        signature::{Keypair, Signer},
+        signature::{keypair_from_seed, Keypair, Signer},
         stake,
         system_instruction::SystemInstruction,
         system_program, system_transaction,
@@ -20,6 +21,55 @@ pub fn test_tx() -> Transaction {
     system_transaction::transfer(&keypair1, &pubkey1, 42, zero)
 }
 
+/// Returns the seed for the fixtures below: `SOLANA_TEST_SEED` if set, or a
+/// random one. The seed is logged, so that a failing run can be replayed by
+/// setting `SOLANA_TEST_SEED` to it.
+pub fn test_seed() -> u64 {
+    let seed = std::env::var("SOLANA_TEST_SEED")
+        .ok()
+        .and_then(|seed| seed.parse().ok())
+        .unwrap_or_else(|| thread_rng().gen());
+    info!("test seed: SOLANA_TEST_SEED={seed}");
+    seed
+}
+
+/// Returns `num_keypairs` keypairs derived from `seed`.
+pub fn test_keypairs(seed: u64, num_keypairs: usize) -> Vec<Keypair> {
+    keypairs_from_rng(&mut ChaChaRng::seed_from_u64(seed), num_keypairs)
+}
+
+fn keypairs_from_rng<R: Rng>(rng: &mut R, num_keypairs: usize) -> Vec<Keypair> {
+    std::iter::repeat_with(|| keypair_from_seed(&rng.gen::<[u8; 32]>()).unwrap())
+        .take(num_keypairs)
+        .collect()
+}
+
+/// Returns `num_accounts` payers and `num_transactions` transfers between
+/// them, all derived from `seed`. The payers are the same as
+/// `test_keypairs(seed, num_accounts)`.
+///
+/// Every transfer is paid by a randomly chosen payer, so transactions sharing
+/// a payer contend for the same write lock; fewer accounts means a denser
+/// conflict graph.
+pub fn test_conflicting_txs(
+    seed: u64,
+    num_accounts: usize,
+    num_transactions: usize,
+    blockhash: Hash,
+) -> (Vec<Keypair>, Vec<Transaction>) {
+    assert!(num_accounts > 0);
+    let mut rng = ChaChaRng::seed_from_u64(seed);
+    let keypairs = keypairs_from_rng(&mut rng, num_accounts);
+    let transactions = std::iter::repeat_with(|| {
+        let from = &keypairs[rng.gen_range(0..num_accounts)];
+        let to = keypairs[rng.gen_range(0..num_accounts)].pubkey();
+        system_transaction::transfer(from, &to, rng.gen_range(1..1_000), blockhash)
+    })
+    .take(num_transactions)
+    .collect();
+    (keypairs, transactions)
+}
+
 pub fn test_invalid_tx() -> Transaction {
     let mut tx = test_tx();
     tx.signatures = vec![Transaction::get_invalid_signature()];
@@ -70,3 +120,41 @@ where
         switch_proof_hash,
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use {super::*, std::collections::HashSet};
+
+    #[test]
+    fn test_conflicting_txs_deterministic() {
+        let blockhash = Hash::new_unique();
+        let (keypairs, transactions) = test_conflicting_txs(42, 4, 32, blockhash);
+        let (other_keypairs, other_transactions) = test_conflicting_txs(42, 4, 32, blockhash);
+        assert_eq!(
+            keypairs.iter().map(Keypair::pubkey).collect::<Vec<_>>(),
+            other_keypairs
+                .iter()
+                .map(Keypair::pubkey)
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(transactions, other_transactions);
+        assert_eq!(transactions.len(), 32);
+        // 32 transfers from 4 payers must share payers.
+        let payers: HashSet<_> = transactions
+            .iter()
+            .map(|tx| tx.message.account_keys[0])
+            .collect();
+        assert!(payers.len() <= 4);
+        assert_eq!(
+            test_keypairs(42, 4)
+                .iter()
+                .map(Keypair::pubkey)
+                .collect::<Vec<_>>(),
+            keypairs.iter().map(Keypair::pubkey).collect::<Vec<_>>()
+        );
+        assert!(transactions.iter().all(|tx| tx.verify().is_ok()));
+
+        let (_, transactions) = test_conflicting_txs(43, 4, 32, blockhash);
+        assert_ne!(transactions, other_transactions);
+    }
+}
@@ -1,52 +1,83 @@
-#![feature(test)]
-extern crate test;
-
 use {
-    solana_entry::entry::{next_entry_mut, Entry, EntrySlice},
+    criterion::{criterion_group, criterion_main, Criterion, Throughput},
+    solana_entry::entry::{hash_transactions, next_entry_mut, Entry, EntrySlice, VerifyRecyclers},
     solana_sdk::{
         hash::{hash, Hash},
         signature::{Keypair, Signer},
         system_transaction,
+        transaction::VersionedTransaction,
     },
-    test::Bencher,
+    std::hint::black_box,
 };
 
 const NUM_HASHES: u64 = 400;
 const NUM_ENTRIES: usize = 800;
+const NUM_TRANSACTIONS: usize = 64;
 
-#[bench]
-fn bench_poh_verify_ticks(bencher: &mut Bencher) {
-    solana_logger::setup();
-    let zero = Hash::default();
-    let start_hash = hash(zero.as_ref());
-    let mut cur_hash = start_hash;
-
-    let mut ticks: Vec<Entry> = Vec::with_capacity(NUM_ENTRIES);
-    for _ in 0..NUM_ENTRIES {
-        ticks.push(next_entry_mut(&mut cur_hash, NUM_HASHES, vec![]));
-    }
-
-    bencher.iter(|| {
-        assert!(ticks.verify(&start_hash));
-    })
+fn create_ticks(start_hash: &Hash) -> Vec<Entry> {
+    let mut cur_hash = *start_hash;
+    (0..NUM_ENTRIES)
+        .map(|_| next_entry_mut(&mut cur_hash, NUM_HASHES, vec![]))
+        .collect()
 }
 
-#[bench]
-fn bench_poh_verify_transaction_entries(bencher: &mut Bencher) {
-    let zero = Hash::default();
-    let start_hash = hash(zero.as_ref());
-    let mut cur_hash = start_hash;
-
+fn create_transaction_entries(start_hash: &Hash) -> Vec<Entry> {
     let keypair1 = Keypair::new();
     let pubkey1 = keypair1.pubkey();
+    let mut cur_hash = *start_hash;
+    (0..NUM_ENTRIES)
+        .map(|_| {
+            let tx = system_transaction::transfer(&keypair1, &pubkey1, 42, cur_hash);
+            next_entry_mut(&mut cur_hash, NUM_HASHES, vec![tx])
+        })
+        .collect()
+}
 
-    let mut ticks: Vec<Entry> = Vec::with_capacity(NUM_ENTRIES);
-    for _ in 0..NUM_ENTRIES {
-        let tx = system_transaction::transfer(&keypair1, &pubkey1, 42, cur_hash);
-        ticks.push(next_entry_mut(&mut cur_hash, NUM_HASHES, vec![tx]));
-    }
+fn bench_poh_verify(c: &mut Criterion) {
+    solana_logger::setup();
+    let start_hash = hash(Hash::default().as_ref());
+    let ticks = create_ticks(&start_hash);
+    let transaction_entries = create_transaction_entries(&start_hash);
 
-    bencher.iter(|| {
-        assert!(ticks.verify(&start_hash));
-    })
+    let mut group = c.benchmark_group("poh_verify");
+    group.throughput(Throughput::Elements(NUM_ENTRIES as u64));
+    group.bench_function("ticks", |b| {
+        b.iter(|| assert!(ticks.verify(&start_hash)));
+    });
+    group.bench_function("ticks_generic", |b| {
+        b.iter(|| assert!(ticks.verify_cpu_generic(&start_hash).finish_verify()));
+    });
+    group.bench_function("transaction_entries", |b| {
+        b.iter(|| assert!(transaction_entries.verify(&start_hash)));
+    });
+    // Uses the GPU when perf libs are available, and falls back to the CPU
+    // otherwise.
+    let recyclers = VerifyRecyclers::default();
+    group.bench_function("start_verify_transaction_entries", |b| {
+        b.iter(|| {
+            assert!(transaction_entries
+                .start_verify(&start_hash, recyclers.clone())
+                .finish_verify())
+        });
+    });
 }
+
+fn bench_hash_transactions(c: &mut Criterion) {
+    let keypair1 = Keypair::new();
+    let pubkey1 = keypair1.pubkey();
+    let transactions: Vec<_> = (0..NUM_TRANSACTIONS)
+        .map(|i| {
+            let tx = system_transaction::transfer(&keypair1, &pubkey1, i as u64, Hash::default());
+            VersionedTransaction::from(tx)
+        })
+        .collect();
+
+    let mut group = c.benchmark_group("hash_transactions");
+    group.throughput(Throughput::Elements(NUM_TRANSACTIONS as u64));
+    group.bench_function("transactions", |b| {
+        b.iter(|| black_box(hash_transactions(&transactions)));
+    });
+}
+
+criterion_group!(benches, bench_poh_verify, bench_hash_transactions);
+criterion_main!(benches);
@@ -554,6 +554,35 @@ impl ProgramTest {
             .push((address, AccountSharedData::from(account)));
     }
 
+    /// Add an initialized SPL Token mint to the test environment
+    pub fn add_spl_token_mint(
+        &mut self,
+        address: Pubkey,
+        mint_authority: &Pubkey,
+        supply: u64,
+        decimals: u8,
+    ) {
+        self.accounts.push((
+            address,
+            programs::spl_token_mint(&Rent::default(), mint_authority, supply, decimals),
+        ));
+    }
+
+    /// Add an initialized SPL Token account holding `amount` tokens of `mint` to the test
+    /// environment
+    pub fn add_spl_token_account(
+        &mut self,
+        address: Pubkey,
+        mint: &Pubkey,
+        owner: &Pubkey,
+        amount: u64,
+    ) {
+        self.accounts.push((
+            address,
+            programs::spl_token_account(&Rent::default(), mint, owner, amount),
+        ));
+    }
+
     /// Add an account to the test environment with the account data in the provided `filename`
     pub fn add_account_with_file_data(
         &mut self,
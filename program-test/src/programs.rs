@@ -1,11 +1,16 @@
-use solana_sdk::{
-    account::{Account, AccountSharedData},
-    bpf_loader_upgradeable::UpgradeableLoaderState,
-    pubkey::Pubkey,
-    rent::Rent,
+use {
+    solana_accounts_db::inline_spl_token::{
+        self, SPL_TOKEN_ACCOUNT_MINT_OFFSET, SPL_TOKEN_ACCOUNT_OWNER_OFFSET,
+    },
+    solana_sdk::{
+        account::{Account, AccountSharedData},
+        bpf_loader_upgradeable::UpgradeableLoaderState,
+        pubkey::{Pubkey, PUBKEY_BYTES},
+        rent::Rent,
+    },
 };
 
-mod spl_token {
+pub mod spl_token {
     solana_sdk::declare_id!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
 }
 mod spl_token_2022 {
@@ -94,3 +99,71 @@ pub fn spl_programs(rent: &Rent) -> Vec<(Pubkey, AccountSharedData)> {
         })
         .collect()
 }
+
+/// Size of an SPL Token `Mint` account
+pub const SPL_TOKEN_MINT_LEN: usize = inline_spl_token::native_mint::ACCOUNT_DATA.len();
+/// Offset of the mint authority in an SPL Token `Mint`, past its `COption` tag
+pub const SPL_TOKEN_MINT_AUTHORITY_OFFSET: usize = 4;
+/// Offset of the supply in an SPL Token `Mint`
+pub const SPL_TOKEN_MINT_SUPPLY_OFFSET: usize = SPL_TOKEN_MINT_AUTHORITY_OFFSET + PUBKEY_BYTES;
+/// Offset of the decimals in an SPL Token `Mint`
+pub const SPL_TOKEN_MINT_DECIMALS_OFFSET: usize = SPL_TOKEN_MINT_SUPPLY_OFFSET + 8;
+const SPL_TOKEN_MINT_IS_INITIALIZED_OFFSET: usize = SPL_TOKEN_MINT_DECIMALS_OFFSET + 1;
+/// Offset of the token amount in an SPL Token `Account`
+pub const SPL_TOKEN_ACCOUNT_AMOUNT_OFFSET: usize = SPL_TOKEN_ACCOUNT_OWNER_OFFSET + PUBKEY_BYTES;
+// Past the amount and the delegate `COption<Pubkey>`
+const SPL_TOKEN_ACCOUNT_STATE_OFFSET: usize =
+    SPL_TOKEN_ACCOUNT_AMOUNT_OFFSET + 8 + 4 + PUBKEY_BYTES;
+
+/// Returns an initialized, rent-exempt SPL Token mint with the given supply
+/// and no freeze authority, so tests can use token flows without submitting
+/// setup transactions.
+pub fn spl_token_mint(
+    rent: &Rent,
+    mint_authority: &Pubkey,
+    supply: u64,
+    decimals: u8,
+) -> AccountSharedData {
+    // All-zero `COption`s are `None`, so only the mint authority, supply,
+    // decimals and initialized flag need to be written.
+    let mut data = vec![0; SPL_TOKEN_MINT_LEN];
+    data[..SPL_TOKEN_MINT_AUTHORITY_OFFSET].copy_from_slice(&1u32.to_le_bytes());
+    data[SPL_TOKEN_MINT_AUTHORITY_OFFSET..SPL_TOKEN_MINT_SUPPLY_OFFSET]
+        .copy_from_slice(mint_authority.as_ref());
+    data[SPL_TOKEN_MINT_SUPPLY_OFFSET..SPL_TOKEN_MINT_DECIMALS_OFFSET]
+        .copy_from_slice(&supply.to_le_bytes());
+    data[SPL_TOKEN_MINT_DECIMALS_OFFSET] = decimals;
+    data[SPL_TOKEN_MINT_IS_INITIALIZED_OFFSET] = 1;
+    AccountSharedData::from(Account {
+        lamports: rent.minimum_balance(data.len()),
+        data,
+        owner: spl_token::id(),
+        executable: false,
+        rent_epoch: 0,
+    })
+}
+
+/// Returns an initialized, rent-exempt SPL Token account of `mint` owned by
+/// `owner` and holding `amount` tokens. The caller is responsible for
+/// accounting for `amount` in the mint's supply.
+pub fn spl_token_account(
+    rent: &Rent,
+    mint: &Pubkey,
+    owner: &Pubkey,
+    amount: u64,
+) -> AccountSharedData {
+    // All-zero `COption`s are `None`, so only the mint, owner, amount and
+    // state need to be written.
+    let mut data = vec![0; inline_spl_token::Account::get_packed_len()];
+    data[SPL_TOKEN_ACCOUNT_MINT_OFFSET..][..PUBKEY_BYTES].copy_from_slice(mint.as_ref());
+    data[SPL_TOKEN_ACCOUNT_OWNER_OFFSET..][..PUBKEY_BYTES].copy_from_slice(owner.as_ref());
+    data[SPL_TOKEN_ACCOUNT_AMOUNT_OFFSET..][..8].copy_from_slice(&amount.to_le_bytes());
+    data[SPL_TOKEN_ACCOUNT_STATE_OFFSET] = 1; // initialized
+    AccountSharedData::from(Account {
+        lamports: rent.minimum_balance(data.len()),
+        data,
+        owner: spl_token::id(),
+        executable: false,
+        rent_epoch: 0,
+    })
+}
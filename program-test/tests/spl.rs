@@ -1,9 +1,17 @@
 use {
-    solana_program_test::{programs::spl_programs, ProgramTest},
+    solana_accounts_db::inline_spl_token::{self, GenericTokenAccount},
+    solana_program_test::{
+        programs::{
+            spl_programs, spl_token, SPL_TOKEN_ACCOUNT_AMOUNT_OFFSET,
+            SPL_TOKEN_MINT_AUTHORITY_OFFSET, SPL_TOKEN_MINT_DECIMALS_OFFSET, SPL_TOKEN_MINT_LEN,
+            SPL_TOKEN_MINT_SUPPLY_OFFSET,
+        },
+        ProgramTest,
+    },
     solana_sdk::{
         bpf_loader, bpf_loader_upgradeable,
         instruction::{AccountMeta, Instruction},
-        pubkey::Pubkey,
+        pubkey::{Pubkey, PUBKEY_BYTES},
         signature::Signer,
         signer::keypair::Keypair,
         system_instruction,
@@ -12,6 +20,10 @@ use {
     },
 };
 
+fn read_u64(data: &[u8], offset: usize) -> u64 {
+    u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap())
+}
+
 #[tokio::test]
 async fn programs_present() {
     let (mut banks_client, _, _) = ProgramTest::default().start().await;
@@ -63,3 +75,93 @@ async fn token_2022() {
 
     banks_client.process_transaction(transaction).await.unwrap();
 }
+
+#[tokio::test]
+async fn prefunded_token_accounts() {
+    let mint = Pubkey::new_unique();
+    let mint_authority = Keypair::new();
+    let source = Pubkey::new_unique();
+    let owner = Keypair::new();
+    let destination = Pubkey::new_unique();
+    let mut program_test = ProgramTest::default();
+    program_test.add_spl_token_mint(mint, &mint_authority.pubkey(), 1_000, 6);
+    program_test.add_spl_token_account(source, &mint, &owner.pubkey(), 1_000);
+    program_test.add_spl_token_account(destination, &mint, &Pubkey::new_unique(), 0);
+    let (mut banks_client, payer, recent_blockhash) = program_test.start().await;
+    let rent = banks_client.get_rent().await.unwrap();
+
+    let mint_account = banks_client.get_account(mint).await.unwrap().unwrap();
+    assert_eq!(mint_account.owner, spl_token::id());
+    assert_eq!(mint_account.data.len(), SPL_TOKEN_MINT_LEN);
+    assert!(rent.is_exempt(mint_account.lamports, SPL_TOKEN_MINT_LEN));
+    assert_eq!(
+        &mint_account.data[SPL_TOKEN_MINT_AUTHORITY_OFFSET..][..PUBKEY_BYTES],
+        mint_authority.pubkey().as_ref()
+    );
+    assert_eq!(
+        read_u64(&mint_account.data, SPL_TOKEN_MINT_SUPPLY_OFFSET),
+        1_000
+    );
+    assert_eq!(mint_account.data[SPL_TOKEN_MINT_DECIMALS_OFFSET], 6);
+
+    let token_account = banks_client.get_account(source).await.unwrap().unwrap();
+    assert_eq!(token_account.owner, spl_token::id());
+    assert!(inline_spl_token::Account::valid_account_data(
+        &token_account.data
+    ));
+    assert_eq!(
+        inline_spl_token::Account::unpack_account_mint(&token_account.data),
+        Some(&mint)
+    );
+    assert_eq!(
+        inline_spl_token::Account::unpack_account_owner(&token_account.data),
+        Some(&owner.pubkey())
+    );
+    assert_eq!(
+        read_u64(&token_account.data, SPL_TOKEN_ACCOUNT_AMOUNT_OFFSET),
+        1_000
+    );
+
+    // The token program must accept the prefunded accounts: mint to the
+    // source account, then transfer part of it out.
+    let amount_instruction = |tag: u8, amount: u64| [&[tag][..], &amount.to_le_bytes()].concat();
+    let transaction = Transaction::new_signed_with_payer(
+        &[
+            Instruction::new_with_bytes(
+                spl_token::id(),
+                &amount_instruction(7, 500), // mint to
+                vec![
+                    AccountMeta::new(mint, false),
+                    AccountMeta::new(source, false),
+                    AccountMeta::new_readonly(mint_authority.pubkey(), true),
+                ],
+            ),
+            Instruction::new_with_bytes(
+                spl_token::id(),
+                &amount_instruction(3, 300), // transfer
+                vec![
+                    AccountMeta::new(source, false),
+                    AccountMeta::new(destination, false),
+                    AccountMeta::new_readonly(owner.pubkey(), true),
+                ],
+            ),
+        ],
+        Some(&payer.pubkey()),
+        &[&payer, &mint_authority, &owner],
+        recent_blockhash,
+    );
+    banks_client.process_transaction(transaction).await.unwrap();
+
+    let mint_account = banks_client.get_account(mint).await.unwrap().unwrap();
+    assert_eq!(
+        read_u64(&mint_account.data, SPL_TOKEN_MINT_SUPPLY_OFFSET),
+        1_500
+    );
+    for (address, balance) in [(source, 1_200u64), (destination, 300)] {
+        let account = banks_client.get_account(address).await.unwrap().unwrap();
+        assert_eq!(
+            read_u64(&account.data, SPL_TOKEN_ACCOUNT_AMOUNT_OFFSET),
+            balance
+        );
+    }
+}
@@ -3,8 +3,8 @@ use {
         config,
         stake_state::{
             authorize, authorize_with_seed, deactivate, deactivate_delinquent, delegate,
-            initialize, merge, new_warmup_cooldown_rate_epoch, redelegate, set_lockup, split,
-            withdraw,
+            initialize, merge, new_warmup_cooldown_rate_epoch, redelegate, rotate_custodian,
+            set_lockup, split, withdraw,
         },
     },
     log::*,
@@ -367,6 +367,17 @@ declare_process_instruction!(Entrypoint, DEFAULT_COMPUTE_UNITS, |invoke_context|
                 Err(InstructionError::InvalidInstructionData)
             }
         }
+        Ok(StakeInstruction::RotateCustodian(new_custodian)) => {
+            let mut me = get_stake_account()?;
+            if invoke_context
+                .feature_set
+                .is_active(&feature_set::stake_rotate_custodian_instruction::id())
+            {
+                rotate_custodian(&mut me, &new_custodian, &signers)
+            } else {
+                Err(InstructionError::InvalidInstructionData)
+            }
+        }
         Err(err) => Err(err),
     }
 });
@@ -3835,6 +3846,117 @@ mod tests {
         );
     }
 
+    #[test_case(Lockup { unix_timestamp: 1, epoch: 1, custodian: Pubkey::new_unique() }; "lockup_in_force")]
+    #[test_case(Lockup { unix_timestamp: 0, epoch: 0, custodian: Pubkey::new_unique() }; "lockup_expired")]
+    fn test_rotate_custodian(lockup: Lockup) {
+        let custodian_address = lockup.custodian;
+        let withdrawer_address = solana_sdk::pubkey::new_rand();
+        let new_custodian_address = solana_sdk::pubkey::new_rand();
+        let stake_address = solana_sdk::pubkey::new_rand();
+        let meta = Meta {
+            authorized: Authorized {
+                staker: stake_address,
+                withdrawer: withdrawer_address,
+            },
+            lockup,
+            ..Meta::default()
+        };
+        let stake_account = AccountSharedData::new_data_with_space(
+            Rent::default().minimum_balance(StakeStateV2::size_of()),
+            &StakeStateV2::Initialized(meta),
+            StakeStateV2::size_of(),
+            &id(),
+        )
+        .unwrap();
+        let instruction_data =
+            serialize(&StakeInstruction::RotateCustodian(new_custodian_address)).unwrap();
+        let transaction_accounts = vec![
+            (stake_address, stake_account),
+            (custodian_address, AccountSharedData::default()),
+            (withdrawer_address, AccountSharedData::default()),
+        ];
+        let mut instruction_accounts = vec![
+            AccountMeta {
+                pubkey: stake_address,
+                is_signer: false,
+                is_writable: true,
+            },
+            AccountMeta {
+                pubkey: custodian_address,
+                is_signer: true,
+                is_writable: false,
+            },
+            AccountMeta {
+                pubkey: withdrawer_address,
+                is_signer: true,
+                is_writable: false,
+            },
+        ];
+
+        // should fail, feature not active
+        let mut feature_set = FeatureSet::all_enabled();
+        feature_set.deactivate(&feature_set::stake_rotate_custodian_instruction::id());
+        process_instruction(
+            Arc::new(feature_set),
+            &instruction_data,
+            transaction_accounts.clone(),
+            instruction_accounts.clone(),
+            Err(InstructionError::InvalidInstructionData),
+        );
+
+        // should fail, custodian not signed
+        instruction_accounts[1].is_signer = false;
+        process_instruction(
+            feature_set_all_enabled(),
+            &instruction_data,
+            transaction_accounts.clone(),
+            instruction_accounts.clone(),
+            Err(InstructionError::MissingRequiredSignature),
+        );
+        instruction_accounts[1].is_signer = true;
+
+        // should fail, withdrawer not signed
+        instruction_accounts[2].is_signer = false;
+        process_instruction(
+            feature_set_all_enabled(),
+            &instruction_data,
+            transaction_accounts.clone(),
+            instruction_accounts.clone(),
+            Err(InstructionError::MissingRequiredSignature),
+        );
+        instruction_accounts[2].is_signer = true;
+
+        // should pass, only the custodian changes
+        let accounts = process_instruction(
+            feature_set_all_enabled(),
+            &instruction_data,
+            transaction_accounts.clone(),
+            instruction_accounts.clone(),
+            Ok(()),
+        );
+        assert_eq!(
+            from(&accounts[0]).unwrap(),
+            StakeStateV2::Initialized(Meta {
+                lockup: Lockup {
+                    custodian: new_custodian_address,
+                    ..lockup
+                },
+                ..meta
+            }),
+        );
+
+        // should fail, the previous custodian can no longer rotate
+        let mut transaction_accounts = transaction_accounts;
+        transaction_accounts[0] = (stake_address, accounts[0].clone());
+        process_instruction(
+            feature_set_all_enabled(),
+            &instruction_data,
+            transaction_accounts,
+            instruction_accounts,
+            Err(InstructionError::MissingRequiredSignature),
+        );
+    }
+
     /// Ensure that `initialize()` respects the minimum balance requirements
     /// - Assert 1: accounts with a balance equal-to the rent exemption initialize OK
     /// - Assert 2: accounts with a balance less-than the rent exemption do not initialize
@@ -369,6 +369,24 @@ pub fn set_lockup(
     }
 }
 
+pub fn rotate_custodian(
+    stake_account: &mut BorrowedAccount,
+    new_custodian: &Pubkey,
+    signers: &HashSet<Pubkey>,
+) -> Result<(), InstructionError> {
+    match stake_account.get_state()? {
+        StakeStateV2::Initialized(mut meta) => {
+            meta.rotate_custodian(new_custodian, signers)?;
+            stake_account.set_state(&StakeStateV2::Initialized(meta))
+        }
+        StakeStateV2::Stake(mut meta, stake, stake_flags) => {
+            meta.rotate_custodian(new_custodian, signers)?;
+            stake_account.set_state(&StakeStateV2::Stake(meta, stake, stake_flags))
+        }
+        _ => Err(InstructionError::InvalidAccountData),
+    }
+}
+
 pub fn split(
     invoke_context: &InvokeContext,
     transaction_context: &TransactionContext,
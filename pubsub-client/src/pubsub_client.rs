@@ -29,6 +29,11 @@
 //! reliably. For a non-blocking, cancelable API, use the asynchronous client
 //! in [`crate::nonblocking::pubsub_client`].
 //!
+//! Subscriptions created with the `*_with_reconnect` methods, like
+//! [`PubsubClient::slot_subscribe_with_reconnect`], reconnect to the server
+//! and resubscribe if the connection is lost, continuing to deliver messages
+//! on the same receiver. See [`PubsubReconnectConfig`].
+//!
 //! By default the [`block_subscribe`] and [`vote_subscribe`] events are
 //! disabled on RPC nodes. They can be enabled by passing
 //! `--rpc-pubsub-enable-block-subscription` and
@@ -114,7 +119,7 @@ use {
         marker::PhantomData,
         net::TcpStream,
         sync::{
-            atomic::{AtomicBool, Ordering},
+            atomic::{AtomicBool, AtomicU64, Ordering},
             Arc, RwLock,
         },
         thread::{sleep, JoinHandle},
@@ -136,7 +141,7 @@ where
     message_type: PhantomData<T>,
     operation: &'static str,
     socket: Arc<RwLock<WebSocket<MaybeTlsStream<TcpStream>>>>,
-    subscription_id: u64,
+    subscription_id: Arc<AtomicU64>,
     t_cleanup: Option<JoinHandle<()>>,
     exit: Arc<AtomicBool>,
 }
@@ -195,12 +200,13 @@ where
     /// the async client in [`crate::nonblocking::pubsub_client`].
     pub fn send_unsubscribe(&self) -> Result<(), PubsubClientError> {
         let method = format!("{}Unsubscribe", self.operation);
+        let subscription_id = self.subscription_id.load(Ordering::Relaxed);
         self.socket
             .write()
             .unwrap()
             .send(Message::Text(
                 json!({
-                "jsonrpc":"2.0","id":1,"method":method,"params":[self.subscription_id]
+                "jsonrpc":"2.0","id":1,"method":method,"params":[subscription_id]
                 })
                 .to_string(),
             ))
@@ -243,6 +249,11 @@ where
         if message.is_ping() {
             return Ok(None);
         }
+        // The server closed the connection, such as when shutting down, which
+        // is as much a websocket error to the receiver as an abrupt close.
+        if message.is_close() {
+            return Err(tungstenite::Error::ConnectionClosed.into());
+        }
         let message_text = &message.into_text()?;
         if let Ok(json_msg) = serde_json::from_str::<Map<String, Value>>(message_text) {
             if let Some(Object(params)) = json_msg.get("params") {
@@ -321,6 +332,30 @@ pub type VoteSubscription = (PubsubVoteClientSubscription, Receiver<RpcVote>);
 pub type PubsubRootClientSubscription = PubsubClientSubscription<Slot>;
 pub type RootSubscription = (PubsubRootClientSubscription, Receiver<Slot>);
 
+/// Reconnection policy for subscriptions created by the `*_with_reconnect`
+/// methods of [`PubsubClient`].
+///
+/// When the websocket connection is lost, the subscription thread reconnects
+/// to the same url and resubscribes with the original parameters, so that
+/// notifications continue to be delivered on the same [`Receiver`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PubsubReconnectConfig {
+    /// Maximum number of consecutive failed reconnection attempts before the
+    /// subscription gives up and its receiver is disconnected.
+    pub max_retries: usize,
+    /// Delay before each reconnection attempt.
+    pub retry_delay: Duration,
+}
+
+impl Default for PubsubReconnectConfig {
+    fn default() -> Self {
+        Self {
+            max_retries: 10,
+            retry_delay: Duration::from_secs(1),
+        }
+    }
+}
+
 /// A client for subscribing to messages from the RPC server.
 ///
 /// See the [module documentation][self].
@@ -403,7 +438,7 @@ impl PubsubClient {
             message_type: PhantomData,
             operation: "account",
             socket,
-            subscription_id,
+            subscription_id: Arc::new(AtomicU64::new(subscription_id)),
             t_cleanup: Some(t_cleanup),
             exit,
         };
@@ -454,7 +489,7 @@ impl PubsubClient {
             message_type: PhantomData,
             operation: "block",
             socket,
-            subscription_id,
+            subscription_id: Arc::new(AtomicU64::new(subscription_id)),
             t_cleanup: Some(t_cleanup),
             exit,
         };
@@ -502,7 +537,7 @@ impl PubsubClient {
             message_type: PhantomData,
             operation: "logs",
             socket,
-            subscription_id,
+            subscription_id: Arc::new(AtomicU64::new(subscription_id)),
             t_cleanup: Some(t_cleanup),
             exit,
         };
@@ -564,7 +599,7 @@ impl PubsubClient {
             message_type: PhantomData,
             operation: "program",
             socket,
-            subscription_id,
+            subscription_id: Arc::new(AtomicU64::new(subscription_id)),
             t_cleanup: Some(t_cleanup),
             exit,
         };
@@ -610,7 +645,7 @@ impl PubsubClient {
             message_type: PhantomData,
             operation: "vote",
             socket,
-            subscription_id,
+            subscription_id: Arc::new(AtomicU64::new(subscription_id)),
             t_cleanup: Some(t_cleanup),
             exit,
         };
@@ -655,7 +690,7 @@ impl PubsubClient {
             message_type: PhantomData,
             operation: "root",
             socket,
-            subscription_id,
+            subscription_id: Arc::new(AtomicU64::new(subscription_id)),
             t_cleanup: Some(t_cleanup),
             exit,
         };
@@ -710,7 +745,7 @@ impl PubsubClient {
             message_type: PhantomData,
             operation: "signature",
             socket,
-            subscription_id,
+            subscription_id: Arc::new(AtomicU64::new(subscription_id)),
             t_cleanup: Some(t_cleanup),
             exit,
         };
@@ -753,7 +788,7 @@ impl PubsubClient {
             message_type: PhantomData,
             operation: "slot",
             socket,
-            subscription_id,
+            subscription_id: Arc::new(AtomicU64::new(subscription_id)),
             t_cleanup: Some(t_cleanup),
             exit,
         };
@@ -803,12 +838,203 @@ impl PubsubClient {
             message_type: PhantomData,
             operation: "slotsUpdates",
             socket,
-            subscription_id,
+            subscription_id: Arc::new(AtomicU64::new(subscription_id)),
             t_cleanup: Some(t_cleanup),
             exit,
         })
     }
 
+    /// Subscribe to account events, reconnecting and resubscribing if the
+    /// connection to the server is lost.
+    ///
+    /// See [`PubsubClient::account_subscribe`] and [`PubsubReconnectConfig`].
+    pub fn account_subscribe_with_reconnect(
+        url: &str,
+        pubkey: &Pubkey,
+        config: Option<RpcAccountInfoConfig>,
+        reconnect_config: PubsubReconnectConfig,
+    ) -> Result<AccountSubscription, PubsubClientError> {
+        let body = json!({
+            "jsonrpc":"2.0",
+            "id":1,
+            "method":"accountSubscribe",
+            "params":[
+                pubkey.to_string(),
+                config
+            ]
+        })
+        .to_string();
+        Self::subscribe_with_reconnect(url, "account", body, |_| false, reconnect_config)
+    }
+
+    /// Subscribe to signature events, reconnecting and resubscribing if the
+    /// connection to the server is lost before the final notification is received.
+    ///
+    /// See [`PubsubClient::signature_subscribe`] and [`PubsubReconnectConfig`].
+    pub fn signature_subscribe_with_reconnect(
+        url: &str,
+        signature: &Signature,
+        config: Option<RpcSignatureSubscribeConfig>,
+        reconnect_config: PubsubReconnectConfig,
+    ) -> Result<SignatureSubscription, PubsubClientError> {
+        let body = json!({
+            "jsonrpc":"2.0",
+            "id":1,
+            "method":"signatureSubscribe",
+            "params":[
+                signature.to_string(),
+                config
+            ]
+        })
+        .to_string();
+        Self::subscribe_with_reconnect(
+            url,
+            "signature",
+            body,
+            |response: &RpcResponse<RpcSignatureResult>| {
+                matches!(response.value, RpcSignatureResult::ProcessedSignature(_))
+            },
+            reconnect_config,
+        )
+    }
+
+    /// Subscribe to slot events, reconnecting and resubscribing if the
+    /// connection to the server is lost.
+    ///
+    /// See [`PubsubClient::slot_subscribe`] and [`PubsubReconnectConfig`].
+    pub fn slot_subscribe_with_reconnect(
+        url: &str,
+        reconnect_config: PubsubReconnectConfig,
+    ) -> Result<SlotsSubscription, PubsubClientError> {
+        let body = json!({
+            "jsonrpc":"2.0",
+            "id":1,
+            "method":"slotSubscribe",
+            "params":[]
+        })
+        .to_string();
+        Self::subscribe_with_reconnect(url, "slot", body, |_| false, reconnect_config)
+    }
+
+    // If is_final returns true for a notification, the server cancels the
+    // subscription after sending it, so the subscription is not renewed
+    // after that. A signature subscription with enableReceivedNotification
+    // first gets a "received" notification, which is not final.
+    fn subscribe_with_reconnect<T>(
+        url: &str,
+        operation: &'static str,
+        body: String,
+        is_final: fn(&T) -> bool,
+        reconnect_config: PubsubReconnectConfig,
+    ) -> Result<(PubsubClientSubscription<T>, Receiver<T>), PubsubClientError>
+    where
+        T: DeserializeOwned + Send + 'static,
+    {
+        let url = Url::parse(url)?;
+        let socket = connect_with_retry(url.clone())?;
+        let (sender, receiver) = unbounded();
+
+        let socket = Arc::new(RwLock::new(socket));
+        let socket_clone = socket.clone();
+        let exit = Arc::new(AtomicBool::new(false));
+        let exit_clone = exit.clone();
+        let subscription_id = PubsubClientSubscription::<T>::send_subscribe(&socket, body.clone())?;
+        let subscription_id = Arc::new(AtomicU64::new(subscription_id));
+        let subscription_id_clone = subscription_id.clone();
+
+        let t_cleanup = std::thread::spawn(move || {
+            let mut finished = false;
+            loop {
+                if exit_clone.load(Ordering::Relaxed) {
+                    break;
+                }
+                match PubsubClientSubscription::read_message(&socket_clone) {
+                    Ok(Some(message)) => {
+                        finished |= is_final(&message);
+                        if let Err(err) = sender.send(message) {
+                            info!("receive error: {:?}", err);
+                        }
+                    }
+                    Ok(None) => {
+                        // Nothing useful, means we received a ping message
+                    }
+                    Err(PubsubClientError::WsError(err)) if !finished => {
+                        info!("websocket error: {:?}, reconnecting", err);
+                        if !Self::resubscribe::<T>(
+                            &url,
+                            &body,
+                            &reconnect_config,
+                            &exit_clone,
+                            &socket_clone,
+                            &subscription_id_clone,
+                        ) {
+                            break;
+                        }
+                    }
+                    Err(err) => {
+                        info!("receive error: {:?}", err);
+                        break;
+                    }
+                }
+            }
+            info!("websocket - exited receive loop");
+        });
+
+        let result = PubsubClientSubscription {
+            message_type: PhantomData,
+            operation,
+            socket,
+            subscription_id,
+            t_cleanup: Some(t_cleanup),
+            exit,
+        };
+
+        Ok((result, receiver))
+    }
+
+    // Reconnects to the server and renews the subscription, replacing the
+    // websocket and subscription id shared with the subscription handle.
+    // Returns false if all attempts failed or the subscription is exiting.
+    fn resubscribe<T>(
+        url: &Url,
+        body: &str,
+        reconnect_config: &PubsubReconnectConfig,
+        exit: &AtomicBool,
+        socket: &Arc<RwLock<WebSocket<MaybeTlsStream<TcpStream>>>>,
+        subscription_id: &AtomicU64,
+    ) -> bool
+    where
+        T: DeserializeOwned,
+    {
+        for attempt in 1..=reconnect_config.max_retries {
+            sleep(reconnect_config.retry_delay);
+            if exit.load(Ordering::Relaxed) {
+                return false;
+            }
+            let new_socket = match connect_with_retry(url.clone()) {
+                Ok(new_socket) => new_socket,
+                Err(err) => {
+                    debug!("reconnect attempt {attempt} failed: {err:?}");
+                    continue;
+                }
+            };
+            *socket.write().unwrap() = new_socket;
+            match PubsubClientSubscription::<T>::send_subscribe(socket, body.to_string()) {
+                Ok(id) => {
+                    subscription_id.store(id, Ordering::Relaxed);
+                    info!("websocket - resubscribed after {attempt} attempt(s)");
+                    return true;
+                }
+                Err(err) => debug!("resubscribe attempt {attempt} failed: {err:?}"),
+            }
+        }
+        warn!(
+            "websocket - unable to reconnect after {} attempts",
+            reconnect_config.max_retries
+        );
+        false
+    }
+
     fn cleanup_with_sender<T>(
         exit: Arc<AtomicBool>,
         socket: &Arc<RwLock<WebSocket<MaybeTlsStream<TcpStream>>>>,
@@ -8,6 +8,7 @@ use {
     solana_account_decoder::UiAccount,
     solana_client::{
         connection_cache::ConnectionCache,
+        thin_client::ThinClient,
         tpu_client::{TpuClient, TpuClientConfig},
     },
     solana_pubsub_client::nonblocking::pubsub_client::PubsubClient,
@@ -19,6 +20,7 @@ use {
         response::{Response as RpcResponse, RpcSignatureResult, SlotUpdate},
     },
     solana_sdk::{
+        client::SyncClient,
         commitment_config::CommitmentConfig,
         hash::Hash,
         pubkey::Pubkey,
@@ -375,11 +377,18 @@ fn test_rpc_subscriptions() {
         );
     }
 
-    let rpc_client = RpcClient::new(test_validator.rpc_url());
-    let mut mint_balance = rpc_client
-        .get_balance_with_commitment(&alice.pubkey(), CommitmentConfig::processed())
+    let rpc_addr = test_validator
+        .rpc_url()
+        .strip_prefix("http://")
         .unwrap()
-        .value;
+        .parse()
+        .unwrap();
+    let connection_cache = Arc::new(ConnectionCache::with_udp("test_rpc_subscriptions", 1));
+    let mut client = ThinClient::new(rpc_addr, *test_validator.tpu(), connection_cache);
+    client.set_pubsub_url(&test_validator.rpc_pubsub_url());
+    let mint_balance = client
+        .get_balance_with_commitment(&alice.pubkey(), CommitmentConfig::processed())
+        .unwrap();
     assert!(mint_balance >= transactions.len() as u64);
 
     // Send all transactions to tpu socket for processing
@@ -390,17 +399,16 @@ fn test_rpc_subscriptions() {
     });
 
     // Track mint balance to know when transactions have completed
-    let now = Instant::now();
     let expected_mint_balance = mint_balance - (transfer_amount * transactions.len() as u64);
-    while mint_balance != expected_mint_balance && now.elapsed() < Duration::from_secs(15) {
-        mint_balance = rpc_client
-            .get_balance_with_commitment(&alice.pubkey(), CommitmentConfig::processed())
-            .unwrap()
-            .value;
-        sleep(Duration::from_millis(100));
-    }
-    if mint_balance != expected_mint_balance {
-        error!("mint-check timeout. mint_balance {:?}", mint_balance);
+    if client
+        .wait_for_balance_with_commitment(
+            &alice.pubkey(),
+            Some(expected_mint_balance),
+            CommitmentConfig::processed(),
+        )
+        .is_none()
+    {
+        error!("mint-check timeout, expected balance {expected_mint_balance}");
     }
 
     // Wait for all signature subscriptions
@@ -7,6 +7,7 @@ pub mod rpc;
 mod rpc_cache;
 pub mod rpc_completed_slots_service;
 pub mod rpc_health;
+mod rpc_method_limiter;
 pub mod rpc_pubsub;
 pub mod rpc_pubsub_service;
 pub mod rpc_service;
@@ -3,6 +3,7 @@ use {
     crate::{
         max_slots::MaxSlots, optimistically_confirmed_bank_tracker::OptimisticallyConfirmedBank,
         parsed_token_accounts::*, rpc_cache::LargestAccountsCache, rpc_health::*,
+        rpc_method_limiter::PeerMetadata,
     },
     base64::{prelude::BASE64_STANDARD, Engine},
     bincode::{config::Options, serialize},
@@ -103,7 +104,7 @@ use {
         cmp::{max, min, Reverse},
         collections::{BinaryHeap, HashMap, HashSet},
         convert::TryFrom,
-        net::SocketAddr,
+        net::{IpAddr, SocketAddr},
         str::FromStr,
         sync::{
             atomic::{AtomicBool, AtomicU64, Ordering},
@@ -143,6 +144,9 @@ pub struct JsonRpcConfig {
     pub enable_extended_tx_metadata_storage: bool,
     pub faucet_addr: Option<SocketAddr>,
     pub health_check_slot_distance: u64,
+    /// Report the node as unhealthy while the accounts write cache holds more than this many
+    /// bytes of account data
+    pub health_check_max_accounts_write_cache_bytes: Option<u64>,
     pub rpc_bigtable_config: Option<RpcBigtableConfig>,
     pub max_multiple_accounts: Option<usize>,
     pub account_indexes: AccountSecondaryIndexes,
@@ -154,6 +158,16 @@ pub struct JsonRpcConfig {
     pub max_request_body_size: Option<usize>,
     /// Disable the health check, used for tests and TestValidator
     pub disable_health_check: bool,
+    /// When not empty, the only RPC methods served; all others are rejected as if they did
+    /// not exist
+    pub allowed_methods: HashSet<String>,
+    /// RPC methods that are rejected as if they did not exist
+    pub disabled_methods: HashSet<String>,
+    /// Maximum requests per second accepted from each client for individual RPC methods
+    pub method_rate_limits: HashMap<String, u64>,
+    /// The rpc port is only reachable through a reverse proxy, so the client address it adds to
+    /// `X-Forwarded-For` can be trusted. Otherwise all clients share one rate limit per method
+    pub trusted_proxy: bool,
 }
 
 impl JsonRpcConfig {
@@ -210,9 +224,16 @@ pub struct JsonRpcRequestProcessor {
     max_complete_transaction_status_slot: Arc<AtomicU64>,
     max_complete_rewards_slot: Arc<AtomicU64>,
     prioritization_fee_cache: Arc<PrioritizationFeeCache>,
+    peer_ip: Option<IpAddr>,
 }
 impl Metadata for JsonRpcRequestProcessor {}
 
+impl PeerMetadata for JsonRpcRequestProcessor {
+    fn peer_ip(&self) -> Option<IpAddr> {
+        self.peer_ip
+    }
+}
+
 impl JsonRpcRequestProcessor {
     pub fn clone_without_bigtable(&self) -> JsonRpcRequestProcessor {
         Self {
@@ -220,6 +241,11 @@ impl JsonRpcRequestProcessor {
             ..self.clone()
         }
     }
+
+    /// Tags the request with the address of the client that sent it
+    pub(crate) fn with_peer_ip(self, peer_ip: Option<IpAddr>) -> Self {
+        Self { peer_ip, ..self }
+    }
 }
 
 impl JsonRpcRequestProcessor {
@@ -348,6 +374,7 @@ impl JsonRpcRequestProcessor {
                 max_complete_transaction_status_slot,
                 max_complete_rewards_slot,
                 prioritization_fee_cache,
+                peer_ip: None,
             },
             receiver,
         )
@@ -408,6 +435,7 @@ impl JsonRpcRequestProcessor {
                 Arc::clone(&optimistically_confirmed_bank),
                 blockstore,
                 0,
+                None,
                 exit,
                 startup_verification_complete,
             )),
@@ -422,6 +450,7 @@ impl JsonRpcRequestProcessor {
             max_complete_transaction_status_slot: Arc::new(AtomicU64::default()),
             max_complete_rewards_slot: Arc::new(AtomicU64::default()),
             prioritization_fee_cache: Arc::new(PrioritizationFeeCache::default()),
+            peer_ip: None,
         }
     }
 
@@ -2650,6 +2679,12 @@ pub mod rpc_minimal {
                     num_slots_behind: Some(num_slots),
                 }
                 .into()),
+                RpcHealthStatus::AccountsWriteCacheFull { .. } => {
+                    Err(RpcCustomError::NodeUnhealthy {
+                        num_slots_behind: None,
+                    }
+                    .into())
+                }
             }
         }
 
@@ -3690,6 +3725,13 @@ pub mod rpc_full {
                         }
                         .into());
                     }
+                    RpcHealthStatus::AccountsWriteCacheFull { .. } => {
+                        inc_new_counter_info!("rpc-send-tx_health-accounts-write-cache-full", 1);
+                        return Err(RpcCustomError::NodeUnhealthy {
+                            num_slots_behind: None,
+                        }
+                        .into());
+                    }
                 }
 
                 if let TransactionSimulationResult {
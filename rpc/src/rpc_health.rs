@@ -12,6 +12,8 @@ use {
 pub enum RpcHealthStatus {
     Ok,
     Behind { num_slots: Slot }, // Validator is behind its known validators
+    // The accounts write cache holds too much account data that is not flushed to storage yet
+    AccountsWriteCacheFull { bytes: u64 },
     Unknown,
 }
 
@@ -19,6 +21,10 @@ pub struct RpcHealth {
     optimistically_confirmed_bank: Arc<RwLock<OptimisticallyConfirmedBank>>,
     blockstore: Arc<Blockstore>,
     health_check_slot_distance: u64,
+    max_accounts_write_cache_bytes: Option<u64>,
+    // Whether the last check found the write cache over the limit, so that the warning is only
+    // logged when that changes rather than on every probe
+    accounts_write_cache_full: AtomicBool,
     override_health_check: Arc<AtomicBool>,
     startup_verification_complete: Arc<AtomicBool>,
     #[cfg(test)]
@@ -30,6 +36,7 @@ impl RpcHealth {
         optimistically_confirmed_bank: Arc<RwLock<OptimisticallyConfirmedBank>>,
         blockstore: Arc<Blockstore>,
         health_check_slot_distance: u64,
+        max_accounts_write_cache_bytes: Option<u64>,
         override_health_check: Arc<AtomicBool>,
         startup_verification_complete: Arc<AtomicBool>,
     ) -> Self {
@@ -37,6 +44,8 @@ impl RpcHealth {
             optimistically_confirmed_bank,
             blockstore,
             health_check_slot_distance,
+            max_accounts_write_cache_bytes,
+            accounts_write_cache_full: AtomicBool::default(),
             override_health_check,
             startup_verification_complete,
             #[cfg(test)]
@@ -59,6 +68,37 @@ impl RpcHealth {
             return RpcHealthStatus::Unknown;
         }
 
+        if let Some(max_accounts_write_cache_bytes) = self.max_accounts_write_cache_bytes {
+            // All banks share the accounts db, and with it the write cache
+            let bytes = self
+                .optimistically_confirmed_bank
+                .read()
+                .unwrap()
+                .bank
+                .memory_usage();
+            let is_full = bytes > max_accounts_write_cache_bytes;
+            if self
+                .accounts_write_cache_full
+                .swap(is_full, Ordering::Relaxed)
+                != is_full
+            {
+                if is_full {
+                    warn!(
+                        "health check: banks hold {bytes} bytes in the accounts write cache, \
+                        limit={max_accounts_write_cache_bytes}",
+                    );
+                } else {
+                    info!(
+                        "health check: accounts write cache is back under the limit: \
+                        {bytes} bytes, limit={max_accounts_write_cache_bytes}",
+                    );
+                }
+            }
+            if is_full {
+                return RpcHealthStatus::AccountsWriteCacheFull { bytes };
+            }
+        }
+
         // A node can observe votes by both replaying blocks and observing gossip.
         //
         // ClusterInfoVoteListener receives votes from both of these sources and then records
@@ -121,6 +161,7 @@ impl RpcHealth {
             optimistically_confirmed_bank,
             blockstore,
             42,
+            None,
             Arc::new(AtomicBool::new(false)),
             Arc::new(AtomicBool::new(true)),
         ))
@@ -141,7 +182,9 @@ pub mod tests {
             get_tmp_ledger_path_auto_delete,
         },
         solana_runtime::{bank::Bank, bank_forks::BankForks},
-        solana_sdk::{clock::UnixTimestamp, hash::Hash, pubkey::Pubkey},
+        solana_sdk::{
+            account::AccountSharedData, clock::UnixTimestamp, hash::Hash, pubkey::Pubkey,
+        },
     };
 
     #[test]
@@ -163,6 +206,7 @@ pub mod tests {
             optimistically_confirmed_bank.clone(),
             blockstore.clone(),
             health_check_slot_distance,
+            None,
             override_health_check.clone(),
             startup_verification_complete,
         );
@@ -211,4 +255,43 @@ pub mod tests {
         optimistically_confirmed_bank.write().unwrap().bank = bank16.clone();
         assert_eq!(health.check(), RpcHealthStatus::Ok);
     }
+
+    #[test]
+    fn test_get_health_accounts_write_cache_full() {
+        let ledger_path = get_tmp_ledger_path_auto_delete!();
+        let blockstore = Arc::new(Blockstore::open(ledger_path.path()).unwrap());
+        let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(100);
+        let bank = Bank::new_for_tests(&genesis_config);
+        let bank_forks = BankForks::new_rw_arc(bank);
+        let optimistically_confirmed_bank =
+            OptimisticallyConfirmedBank::locked_from_bank_forks_root(&bank_forks);
+        let bank0 = bank_forks.read().unwrap().root_bank();
+        bank0.set_startup_verification_complete();
+        blockstore
+            .insert_optimistic_slot(0, &Hash::default(), UnixTimestamp::default())
+            .unwrap();
+
+        let cache_bytes = bank_forks.read().unwrap().root_bank().memory_usage();
+        let health = RpcHealth::new(
+            optimistically_confirmed_bank,
+            blockstore,
+            10,
+            Some(cache_bytes + 100),
+            Arc::new(AtomicBool::new(false)),
+            Arc::clone(bank0.get_startup_verification_complete()),
+        );
+        assert_eq!(health.check(), RpcHealthStatus::Ok);
+
+        // Past the limit the node is reported as unhealthy
+        bank0.store_account(
+            &Pubkey::new_unique(),
+            &AccountSharedData::new(1, 101, &Pubkey::default()),
+        );
+        assert_eq!(
+            health.check(),
+            RpcHealthStatus::AccountsWriteCacheFull {
+                bytes: bank_forks.read().unwrap().root_bank().memory_usage()
+            }
+        );
+    }
 }
@@ -0,0 +1,363 @@
+//! The `rpc_method_limiter` module lets node operators restrict which JSON RPC
+//! methods are served and throttle expensive ones for each client.
+
+use {
+    jsonrpc_core::{
+        futures::future::{self, Either},
+        middleware::{Middleware, NoopCallFuture, NoopFuture},
+        Call, Error, Metadata, Output,
+    },
+    lru::LruCache,
+    solana_rpc_client_api::custom_error::RpcCustomError,
+    std::{
+        collections::{HashMap, HashSet},
+        future::Future,
+        net::IpAddr,
+        sync::Mutex,
+        time::{Duration, Instant},
+    },
+};
+
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+
+// Clients tracked per method; the least recently seen client is forgotten
+// when another one comes in
+const MAX_TRACKED_PEERS: usize = 65_536;
+
+/// Request metadata that identifies the client sending the request.
+pub(crate) trait PeerMetadata: Metadata {
+    /// Address of the client, None if it is unknown
+    fn peer_ip(&self) -> Option<IpAddr>;
+}
+
+/// Token bucket allowing bursts of up to one second worth of requests.
+///
+/// Tracks the time at which the bucket would be full again if no further
+/// requests came in (the generic cell rate algorithm); each request pushes it
+/// forward by the interval between two requests at the configured rate.
+#[derive(Debug, Default)]
+struct TokenBucket {
+    // Nanoseconds since the limiter was created
+    theoretical_arrival_time: u64,
+}
+
+impl TokenBucket {
+    /// Takes a token, or returns how long until one becomes available.
+    fn try_acquire(&mut self, now: u64, request_interval: u64) -> Result<(), Duration> {
+        let burst_deadline = now.saturating_add(NANOS_PER_SECOND);
+        let next = self
+            .theoretical_arrival_time
+            .max(now)
+            .saturating_add(request_interval);
+        if next > burst_deadline {
+            return Err(Duration::from_nanos(next - burst_deadline));
+        }
+        self.theoretical_arrival_time = next;
+        Ok(())
+    }
+}
+
+/// Rate limit of one method, with a separate token bucket for each client.
+#[derive(Debug)]
+struct MethodRateLimit {
+    // Nanoseconds between two requests at the configured rate
+    request_interval: u64,
+    // Clients without a known address share one bucket
+    buckets: Mutex<LruCache<Option<IpAddr>, TokenBucket>>,
+}
+
+impl MethodRateLimit {
+    fn new(requests_per_second: u64) -> Self {
+        Self {
+            request_interval: NANOS_PER_SECOND / requests_per_second,
+            buckets: Mutex::new(LruCache::new(MAX_TRACKED_PEERS)),
+        }
+    }
+
+    fn try_acquire(&self, peer_ip: Option<IpAddr>, now: u64) -> Result<(), Duration> {
+        let mut buckets = self.buckets.lock().unwrap();
+        if let Some(bucket) = buckets.get_mut(&peer_ip) {
+            return bucket.try_acquire(now, self.request_interval);
+        }
+        let mut bucket = TokenBucket::default();
+        let result = bucket.try_acquire(now, self.request_interval);
+        buckets.put(peer_ip, bucket);
+        result
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct RpcMethodLimiter {
+    allowed_methods: HashSet<String>,
+    disabled_methods: HashSet<String>,
+    rate_limits: HashMap<String, MethodRateLimit>,
+    start: Instant,
+}
+
+impl RpcMethodLimiter {
+    pub(crate) fn new(
+        allowed_methods: HashSet<String>,
+        mut disabled_methods: HashSet<String>,
+        method_rate_limits: &HashMap<String, u64>,
+    ) -> Self {
+        let mut rate_limits = HashMap::new();
+        for (method, rate) in method_rate_limits {
+            if *rate == 0 {
+                disabled_methods.insert(method.clone());
+            } else {
+                rate_limits.insert(method.clone(), MethodRateLimit::new(*rate));
+            }
+        }
+        Self {
+            allowed_methods,
+            disabled_methods,
+            rate_limits,
+            start: Instant::now(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.allowed_methods.is_empty()
+            && self.disabled_methods.is_empty()
+            && self.rate_limits.is_empty()
+    }
+
+    fn check(&self, method: &str, peer_ip: Option<IpAddr>, now: Instant) -> Result<(), Error> {
+        if (!self.allowed_methods.is_empty() && !self.allowed_methods.contains(method))
+            || self.disabled_methods.contains(method)
+        {
+            return Err(Error::method_not_found());
+        }
+        if let Some(rate_limit) = self.rate_limits.get(method) {
+            let now = u64::try_from(now.saturating_duration_since(self.start).as_nanos())
+                .unwrap_or(u64::MAX);
+            if let Err(retry_after) = rate_limit.try_acquire(peer_ip, now) {
+                inc_new_counter_info!("rpc-method-rate-limited", 1);
+                return Err(RpcCustomError::MethodRateLimited {
+                    method: method.to_string(),
+                    retry_after_ms: u64::try_from(retry_after.as_millis().max(1))
+                        .unwrap_or(u64::MAX),
+                }
+                .into());
+            }
+        }
+        Ok(())
+    }
+}
+
+impl<M: PeerMetadata> Middleware<M> for RpcMethodLimiter {
+    type Future = NoopFuture;
+    type CallFuture = NoopCallFuture;
+
+    fn on_call<F, X>(&self, call: Call, meta: M, next: F) -> Either<Self::CallFuture, X>
+    where
+        F: Fn(Call, M) -> X + Send + Sync,
+        X: Future<Output = Option<Output>> + Send + 'static,
+    {
+        if self.is_empty() {
+            return Either::Right(next(call, meta));
+        }
+        if let Call::MethodCall(request) = &call {
+            if let Err(err) = self.check(&request.method, meta.peer_ip(), Instant::now()) {
+                let output = Output::from(Err(err), request.id.clone(), request.jsonrpc);
+                return Either::Left(Box::pin(future::ready(Some(output))));
+            }
+        }
+        Either::Right(next(call, meta))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use {
+        super::*,
+        jsonrpc_core::{ErrorCode, MetaIoHandler, Value},
+        serde_json::json,
+        solana_rpc_client_api::custom_error::JSON_RPC_SERVER_ERROR_METHOD_RATE_LIMITED,
+        std::net::Ipv4Addr,
+    };
+
+    #[derive(Clone, Default)]
+    struct TestMetadata(Option<IpAddr>);
+
+    impl Metadata for TestMetadata {}
+
+    impl PeerMetadata for TestMetadata {
+        fn peer_ip(&self) -> Option<IpAddr> {
+            self.0
+        }
+    }
+
+    fn peer(last_octet: u8) -> Option<IpAddr> {
+        Some(IpAddr::V4(Ipv4Addr::new(10, 0, 0, last_octet)))
+    }
+
+    #[test]
+    fn test_disabled_method() {
+        let limiter = RpcMethodLimiter::new(
+            HashSet::new(),
+            HashSet::from(["getProgramAccounts".to_string()]),
+            &HashMap::new(),
+        );
+        let now = Instant::now();
+        assert_eq!(
+            limiter
+                .check("getProgramAccounts", None, now)
+                .unwrap_err()
+                .code,
+            ErrorCode::MethodNotFound
+        );
+        assert!(limiter.check("getSlot", None, now).is_ok());
+    }
+
+    #[test]
+    fn test_allowed_methods() {
+        let limiter = RpcMethodLimiter::new(
+            HashSet::from(["getSlot".to_string(), "getBlock".to_string()]),
+            HashSet::from(["getBlock".to_string()]),
+            &HashMap::new(),
+        );
+        let now = Instant::now();
+        assert!(limiter.check("getSlot", None, now).is_ok());
+        // the denylist wins over the allowlist
+        assert_eq!(
+            limiter.check("getBlock", None, now).unwrap_err().code,
+            ErrorCode::MethodNotFound
+        );
+        assert_eq!(
+            limiter
+                .check("getProgramAccounts", None, now)
+                .unwrap_err()
+                .code,
+            ErrorCode::MethodNotFound
+        );
+    }
+
+    #[test]
+    fn test_method_rate_limit() {
+        let limiter = RpcMethodLimiter::new(
+            HashSet::new(),
+            HashSet::new(),
+            &HashMap::from([("getBlock".to_string(), 2)]),
+        );
+        let now = Instant::now();
+        assert!(limiter.check("getBlock", peer(1), now).is_ok());
+        assert!(limiter.check("getBlock", peer(1), now).is_ok());
+        let err = limiter.check("getBlock", peer(1), now).unwrap_err();
+        assert_eq!(
+            err.code,
+            ErrorCode::ServerError(JSON_RPC_SERVER_ERROR_METHOD_RATE_LIMITED)
+        );
+        assert_eq!(err.data.unwrap()["retryAfterMs"], 500);
+        // other clients have their own bucket
+        assert!(limiter.check("getBlock", peer(2), now).is_ok());
+        assert!(limiter.check("getBlock", None, now).is_ok());
+        // other methods are not throttled
+        for _ in 0..10 {
+            assert!(limiter.check("getSlot", peer(1), now).is_ok());
+        }
+        // the bucket refills over time
+        let later = now + Duration::from_millis(500);
+        assert!(limiter.check("getBlock", peer(1), later).is_ok());
+        assert!(limiter.check("getBlock", peer(1), later).is_err());
+    }
+
+    #[test]
+    fn test_method_rate_limit_forgets_least_recent_peer() {
+        let rate_limit = MethodRateLimit::new(1);
+        let now = NANOS_PER_SECOND;
+        assert!(rate_limit.try_acquire(peer(1), now).is_ok());
+        for i in 0..MAX_TRACKED_PEERS {
+            let peer_ip = IpAddr::V4(Ipv4Addr::from(i as u32));
+            assert!(rate_limit.try_acquire(Some(peer_ip), now).is_ok());
+            // the throttled client keeps its bucket while it keeps calling
+            assert!(rate_limit.try_acquire(peer(1), now).is_err());
+        }
+        let buckets = rate_limit.buckets.lock().unwrap();
+        assert_eq!(buckets.len(), MAX_TRACKED_PEERS);
+        assert!(buckets.contains(&peer(1)));
+        assert!(!buckets.contains(&Some(IpAddr::V4(Ipv4Addr::from(0)))));
+    }
+
+    #[test]
+    fn test_zero_rate_limit_disables_method() {
+        let limiter = RpcMethodLimiter::new(
+            HashSet::new(),
+            HashSet::new(),
+            &HashMap::from([("getBlock".to_string(), 0)]),
+        );
+        assert_eq!(
+            limiter
+                .check("getBlock", None, Instant::now())
+                .unwrap_err()
+                .code,
+            ErrorCode::MethodNotFound
+        );
+    }
+
+    #[test]
+    fn test_method_limiter_middleware() {
+        let mut io = MetaIoHandler::<TestMetadata, _>::with_middleware(RpcMethodLimiter::new(
+            HashSet::new(),
+            HashSet::from(["getProgramAccounts".to_string()]),
+            &HashMap::from([("getBlock".to_string(), 2)]),
+        ));
+        for method in ["getProgramAccounts", "getBlock", "getSlot"] {
+            io.add_sync_method(method, |_| Ok(Value::from(42)));
+        }
+        let handle = |request: Value, peer_ip: Option<IpAddr>| -> Value {
+            let response = io
+                .handle_request_sync(&request.to_string(), TestMetadata(peer_ip))
+                .unwrap();
+            serde_json::from_str(&response).unwrap()
+        };
+
+        // A disabled method is reported as missing, even though it is registered
+        let response = handle(
+            json!({"jsonrpc": "2.0", "id": 1, "method": "getProgramAccounts"}),
+            peer(1),
+        );
+        assert_eq!(response["error"]["code"], -32601);
+        let response = handle(
+            json!({"jsonrpc": "2.0", "id": 1, "method": "getSlot"}),
+            peer(1),
+        );
+        assert_eq!(response["result"], 42);
+
+        // Each call of a batch is checked on its own
+        let request = |id| json!({"jsonrpc": "2.0", "id": id, "method": "getBlock"});
+        let response = handle(json!([request(1), request(2), request(3)]), peer(1));
+        assert_eq!(response[0]["result"], 42);
+        assert_eq!(response[1]["result"], 42);
+        assert_eq!(response[2]["id"], 3);
+        assert_eq!(
+            response[2]["error"]["code"],
+            JSON_RPC_SERVER_ERROR_METHOD_RATE_LIMITED
+        );
+        assert!(
+            response[2]["error"]["data"]["retryAfterMs"]
+                .as_u64()
+                .unwrap()
+                > 0
+        );
+
+        // Another client is not affected
+        let response = handle(request(4), peer(2));
+        assert_eq!(response["result"], 42);
+    }
+
+    #[test]
+    fn test_method_limiter_empty() {
+        let limiter = RpcMethodLimiter::new(HashSet::new(), HashSet::new(), &HashMap::new());
+        assert!(limiter.is_empty());
+        let mut io = MetaIoHandler::<TestMetadata, _>::with_middleware(limiter);
+        io.add_sync_method("getSlot", |_| Ok(Value::from(42)));
+        let response = io
+            .handle_request_sync(
+                r#"{"jsonrpc":"2.0","id":1,"method":"getSlot"}"#,
+                TestMetadata::default(),
+            )
+            .unwrap();
+        assert_eq!(response, r#"{"jsonrpc":"2.0","result":42,"id":1}"#);
+    }
+}
@@ -12,6 +12,7 @@ use {
         },
         rpc_cache::LargestAccountsCache,
         rpc_health::*,
+        rpc_method_limiter::RpcMethodLimiter,
     },
     crossbeam_channel::unbounded,
     jsonrpc_core::{futures::prelude::*, MetaIoHandler},
@@ -43,7 +44,7 @@ use {
     solana_send_transaction_service::send_transaction_service::{self, SendTransactionService},
     solana_storage_bigtable::CredentialType,
     std::{
-        net::SocketAddr,
+        net::{IpAddr, SocketAddr},
         path::{Path, PathBuf},
         sync::{
             atomic::{AtomicBool, AtomicU64, Ordering},
@@ -237,6 +238,7 @@ impl RpcRequestMiddleware {
         let response = match self.health.check() {
             RpcHealthStatus::Ok => "ok",
             RpcHealthStatus::Behind { .. } => "behind",
+            RpcHealthStatus::AccountsWriteCacheFull { .. } => "accounts-write-cache-full",
             RpcHealthStatus::Unknown => "unknown",
         };
         info!("health check: {}", response);
@@ -335,6 +337,24 @@ fn process_rest(bank_forks: &Arc<RwLock<BankForks>>, path: &str) -> Option<Strin
     }
 }
 
+/// Returns the address of the client that sent `req`, as recorded by the reverse proxy in front
+/// of the rpc service. The http server does not expose the address of the connection itself, so
+/// the last `X-Forwarded-For` entry, the one added by the closest proxy, is used. Only meaningful
+/// with `JsonRpcConfig::trusted_proxy`, since a client connecting directly can set any value.
+fn request_peer_ip(req: &hyper::Request<hyper::Body>) -> Option<IpAddr> {
+    req.headers()
+        .get_all("x-forwarded-for")
+        .iter()
+        .last()?
+        .to_str()
+        .ok()?
+        .rsplit(',')
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+}
+
 impl JsonRpcService {
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -370,6 +390,7 @@ impl JsonRpcService {
             Arc::clone(&optimistically_confirmed_bank),
             Arc::clone(&blockstore),
             config.health_check_slot_distance,
+            config.health_check_max_accounts_write_cache_bytes,
             override_health_check,
             startup_verification_complete,
         ));
@@ -454,7 +475,13 @@ impl JsonRpcService {
             };
 
         let full_api = config.full_api;
+        let trusted_proxy = config.trusted_proxy;
         let obsolete_v1_7_api = config.obsolete_v1_7_api;
+        let method_limiter = RpcMethodLimiter::new(
+            config.allowed_methods.clone(),
+            config.disabled_methods.clone(),
+            &config.method_rate_limits,
+        );
         let max_request_body_size = config
             .max_request_body_size
             .unwrap_or(MAX_REQUEST_BODY_SIZE);
@@ -501,7 +528,7 @@ impl JsonRpcService {
             .spawn(move || {
                 renice_this_thread(rpc_niceness_adj).unwrap();
 
-                let mut io = MetaIoHandler::default();
+                let mut io = MetaIoHandler::with_middleware(method_limiter);
 
                 io.extend_with(rpc_minimal::MinimalImpl.to_delegate());
                 if full_api {
@@ -527,11 +554,18 @@ impl JsonRpcService {
                     io,
                     move |req: &hyper::Request<hyper::Body>| {
                         let xbigtable = req.headers().get("x-bigtable");
-                        if xbigtable.is_some_and(|v| v == "disabled") {
+                        let request_processor = if xbigtable.is_some_and(|v| v == "disabled") {
                             request_processor.clone_without_bigtable()
                         } else {
                             request_processor.clone()
-                        }
+                        };
+                        // Any client can set X-Forwarded-For when connecting directly
+                        let peer_ip = if trusted_proxy {
+                            request_peer_ip(req)
+                        } else {
+                            None
+                        };
+                        request_processor.with_peer_ip(peer_ip)
                     },
                 )
                 .event_loop_executor(runtime.handle().clone())
@@ -605,10 +639,7 @@ mod tests {
             genesis_config::{ClusterType, DEFAULT_GENESIS_ARCHIVE},
             signature::Signer,
         },
-        std::{
-            io::Write,
-            net::{IpAddr, Ipv4Addr},
-        },
+        std::{io::Write, net::Ipv4Addr},
         tokio::runtime::Runtime,
     };
 
@@ -699,6 +730,30 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_request_peer_ip() {
+        let request = |forwarded_for: &[&str]| {
+            let mut request = hyper::Request::builder();
+            for value in forwarded_for {
+                request = request.header("x-forwarded-for", *value);
+            }
+            request.body(hyper::Body::empty()).unwrap()
+        };
+        let ip = |ip: &str| Some(ip.parse::<IpAddr>().unwrap());
+
+        assert_eq!(request_peer_ip(&request(&[])), None);
+        assert_eq!(request_peer_ip(&request(&["10.0.0.1"])), ip("10.0.0.1"));
+        assert_eq!(
+            request_peer_ip(&request(&["1.2.3.4, 10.0.0.1"])),
+            ip("10.0.0.1")
+        );
+        assert_eq!(
+            request_peer_ip(&request(&["1.2.3.4", "2001:db8::1"])),
+            ip("2001:db8::1")
+        );
+        assert_eq!(request_peer_ip(&request(&["unknown"])), None);
+    }
+
     #[test]
     fn test_strip_prefix() {
         assert_eq!(RpcRequestMiddleware::strip_leading_slash("/"), Some(""));
@@ -4491,6 +4491,24 @@ impl Bank {
             .is_some()
     }
 
+    /// Returns, for each `(message_hash, recent_blockhash)` pair, whether that
+    /// message was already processed on this bank's fork. The status cache is
+    /// locked once for the whole batch.
+    pub fn check_already_processed<'a>(
+        &self,
+        messages: impl IntoIterator<Item = (&'a Hash, &'a Hash)>,
+    ) -> Vec<bool> {
+        let rcache = self.status_cache.read().unwrap();
+        messages
+            .into_iter()
+            .map(|(message_hash, recent_blockhash)| {
+                rcache
+                    .get_status(message_hash, recent_blockhash, &self.ancestors)
+                    .is_some()
+            })
+            .collect()
+    }
+
     fn check_status_cache(
         &self,
         sanitized_txs: &[impl core::borrow::Borrow<SanitizedTransaction>],
@@ -5975,6 +5993,13 @@ impl Bank {
         self.rc.accounts.clone()
     }
 
+    /// Returns the number of bytes of account data held in the accounts write cache, which all
+    /// banks of a fork graph share. This includes rooted slots that are not flushed to storage
+    /// yet, which is what grows when flushing falls behind.
+    pub fn memory_usage(&self) -> u64 {
+        self.rc.accounts.accounts_db.accounts_cache.size()
+    }
+
     fn finish_init(
         &mut self,
         genesis_config: &GenesisConfig,
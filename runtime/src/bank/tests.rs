@@ -3287,6 +3287,39 @@ fn test_tx_already_processed() {
     );
 }
 
+#[test]
+fn test_check_already_processed() {
+    let (genesis_config, mint_keypair) = create_genesis_config(sol_to_lamports(1.));
+    let (parent, bank_forks) = Bank::new_with_bank_forks_for_tests(&genesis_config);
+    let amount = genesis_config.rent.minimum_balance(0);
+
+    let processed_tx = system_transaction::transfer(
+        &mint_keypair,
+        &solana_sdk::pubkey::new_rand(),
+        amount,
+        genesis_config.hash(),
+    );
+    let pending_tx = system_transaction::transfer(
+        &mint_keypair,
+        &solana_sdk::pubkey::new_rand(),
+        amount,
+        genesis_config.hash(),
+    );
+    assert_eq!(parent.process_transaction(&processed_tx), Ok(()));
+    let bank = new_from_parent_with_fork_next_slot(parent, bank_forks.as_ref());
+
+    let message_hashes = [processed_tx.message().hash(), pending_tx.message().hash()];
+    let blockhash = genesis_config.hash();
+    assert_eq!(
+        bank.check_already_processed(
+            message_hashes
+                .iter()
+                .map(|message_hash| (message_hash, &blockhash))
+        ),
+        vec![true, false]
+    );
+}
+
 /// Verifies that last ids and status cache are correctly referenced from parent
 #[test]
 fn test_bank_parent_already_processed() {
@@ -49,6 +49,8 @@ struct SetRootMetrics {
     total_parent_banks: i64,
     tx_count: i64,
     dropped_banks_len: i64,
+    pruned_accounts_cache_bytes: i64,
+    accounts_write_cache_bytes: i64,
     accounts_data_len: i64,
 }
 
@@ -424,6 +426,9 @@ impl BankForks {
         let mut drop_parent_banks_time = Measure::start("set_root::drop_banks");
         drop(parents);
         drop_parent_banks_time.stop();
+        let pruned_accounts_cache_bytes =
+            freed_accounts_write_cache_bytes(&removed_banks, &self.banks[&root]);
+        let accounts_write_cache_bytes = self.banks[&root].memory_usage();
 
         (
             removed_banks,
@@ -439,6 +444,8 @@ impl BankForks {
                 total_parent_banks: total_parent_banks as i64,
                 tx_count: (new_tx_count - root_tx_count) as i64,
                 dropped_banks_len: dropped_banks_len as i64,
+                pruned_accounts_cache_bytes: pruned_accounts_cache_bytes as i64,
+                accounts_write_cache_bytes: accounts_write_cache_bytes as i64,
                 accounts_data_len,
             },
         )
@@ -546,6 +553,16 @@ impl BankForks {
                 i64
             ),
             ("dropped_banks_len", set_root_metrics.dropped_banks_len, i64),
+            (
+                "pruned_accounts_cache_bytes",
+                set_root_metrics.pruned_accounts_cache_bytes,
+                i64
+            ),
+            (
+                "accounts_write_cache_bytes",
+                set_root_metrics.accounts_write_cache_bytes,
+                i64
+            ),
             ("accounts_data_len", set_root_metrics.accounts_data_len, i64),
         );
         removed_banks
@@ -674,6 +691,31 @@ impl BankForks {
     }
 }
 
+// Write cache bytes of the abandoned forks that are freed along with `removed_banks`. Ancestors
+// of the new root are removed too, but their slots are rooted: dropping them purges nothing and
+// the flush service reclaims their cache later. A bank that is still referenced elsewhere, e.g.
+// by replay or an RPC request, keeps its cache alive. Within the list, a pruned bank can also be
+// referenced as the parent of another pruned bank.
+fn freed_accounts_write_cache_bytes(removed_banks: &[Arc<Bank>], root_bank: &Bank) -> u64 {
+    let mut num_children = HashMap::<Slot, usize>::new();
+    for parent in removed_banks.iter().filter_map(|bank| bank.parent()) {
+        *num_children.entry(parent.slot()).or_default() += 1;
+    }
+    removed_banks
+        .iter()
+        .filter(|bank| !root_bank.ancestors.contains_key(&bank.slot()))
+        .filter(|bank| {
+            let num_children = num_children.get(&bank.slot()).copied().unwrap_or_default();
+            Arc::strong_count(bank).saturating_sub(1) == num_children
+        })
+        .filter_map(|bank| {
+            let accounts_cache = &bank.rc.accounts.accounts_db.accounts_cache;
+            accounts_cache.slot_cache(bank.slot())
+        })
+        .map(|slot_cache| slot_cache.total_bytes())
+        .sum()
+}
+
 impl ForkGraph for BankForks {
     fn relationship(&self, a: Slot, b: Slot) -> BlockRelation {
         let known_slot_range = self.root()..=self.highest_slot();
@@ -716,6 +758,7 @@ mod tests {
         assert_matches::assert_matches,
         solana_accounts_db::epoch_accounts_hash::EpochAccountsHash,
         solana_sdk::{
+            account::AccountSharedData,
             clock::UnixTimestamp,
             epoch_schedule::EpochSchedule,
             hash::Hash,
@@ -981,6 +1024,65 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_bank_forks_memory_usage() {
+        let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(10_000);
+        let bank = Bank::new_for_tests(&genesis_config);
+        let bank_forks = BankForks::new_rw_arc(bank);
+        let parent_child_pairs = vec![(0, 1), (0, 2)];
+        extend_bank_forks(bank_forks.clone(), &parent_child_pairs);
+        let cache_bytes = bank_forks.read().unwrap().root_bank().memory_usage();
+
+        // Writes on the fork that is about to be abandoned
+        let bank1 = bank_forks.read().unwrap().get(1).unwrap();
+        bank1.store_account(
+            &Pubkey::new_unique(),
+            &AccountSharedData::new(1, 100, &Pubkey::default()),
+        );
+        assert_eq!(
+            bank_forks.read().unwrap().root_bank().memory_usage(),
+            cache_bytes + 100
+        );
+
+        // Freeze the new root up front, so that set_root does not write to it. Rooting does not
+        // flush the cache and bank1 is still held here, so nothing is released yet.
+        let bank2 = bank_forks.read().unwrap().get(2).unwrap();
+        bank2.freeze();
+        let cache_bytes = bank_forks.read().unwrap().root_bank().memory_usage();
+        let removed_banks = bank_forks.write().unwrap().set_root(
+            2,
+            &AbsRequestSender::default(),
+            None, // highest confirmed root
+        );
+        assert!(removed_banks.iter().any(|bank| bank.slot() == 1));
+        assert_eq!(
+            bank_forks.read().unwrap().root_bank().memory_usage(),
+            cache_bytes
+        );
+
+        // bank0 is an ancestor of the new root, so its rooted writes are flushed rather than
+        // freed. bank1 is abandoned, but still held here.
+        let root_bank = bank_forks.read().unwrap().root_bank();
+        assert!(removed_banks.iter().any(|bank| bank.slot() == 0));
+        assert_eq!(
+            freed_accounts_write_cache_bytes(&removed_banks, &root_bank),
+            0
+        );
+        let bank1_bytes = bank1
+            .rc
+            .accounts
+            .accounts_db
+            .accounts_cache
+            .slot_cache(1)
+            .unwrap()
+            .total_bytes();
+        drop(bank1);
+        assert_eq!(
+            freed_accounts_write_cache_bytes(&removed_banks, &root_bank),
+            bank1_bytes
+        );
+    }
+
     #[test]
     fn test_bank_forks_with_highest_super_majority_root() {
         let GenesisConfigInfo { genesis_config, .. } = create_genesis_config(10_000);
@@ -1,3 +1,5 @@
+#[cfg(feature = "dev-context-only-utils")]
+use solana_perf::test_tx::test_keypairs;
 use {
     solana_accounts_db::inline_spl_token,
     solana_sdk::{
@@ -90,6 +92,26 @@ pub fn create_genesis_config(mint_lamports: u64) -> GenesisConfigInfo {
     )
 }
 
+/// Like [`create_genesis_config`], with an account funded with each of
+/// `balances` lamports. Returns the accounts' keypairs, which are
+/// `test_keypairs(seed, balances.len())`.
+#[cfg(feature = "dev-context-only-utils")]
+pub fn create_genesis_config_with_funded_accounts(
+    mint_lamports: u64,
+    seed: u64,
+    balances: &[u64],
+) -> (GenesisConfigInfo, Vec<Keypair>) {
+    let mut genesis_config_info = create_genesis_config(mint_lamports);
+    let keypairs = test_keypairs(seed, balances.len());
+    for (keypair, lamports) in keypairs.iter().zip(balances) {
+        genesis_config_info.genesis_config.add_account(
+            keypair.pubkey(),
+            AccountSharedData::new(*lamports, 0, &system_program::id()),
+        );
+    }
+    (genesis_config_info, keypairs)
+}
+
 pub fn create_genesis_config_with_vote_accounts(
     mint_lamports: u64,
     voting_keypairs: &[impl Borrow<ValidatorVoteKeypairs>],
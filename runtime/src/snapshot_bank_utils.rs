@@ -1278,7 +1278,7 @@ mod tests {
             native_token::{sol_to_lamports, LAMPORTS_PER_SOL},
             signature::{Keypair, Signer},
             system_transaction,
-            transaction::SanitizedTransaction,
+            transaction::{SanitizedTransaction, TransactionError},
         },
         std::sync::{atomic::Ordering, Arc, RwLock},
     };
@@ -1467,6 +1467,92 @@ mod tests {
         assert_eq!(*bank4, roundtrip_bank);
     }
 
+    /// Test that the status cache survives a roundtrip through a full snapshot, so that a
+    /// transaction processed before the snapshot is rejected as a duplicate by a bank restored
+    /// from it.
+    #[test]
+    fn test_roundtrip_bank_to_and_from_full_snapshot_rejects_processed_transaction() {
+        let collector = Pubkey::new_unique();
+        let key1 = Keypair::new();
+        let (genesis_config, mint_keypair) = create_genesis_config(sol_to_lamports(1_000_000.));
+        let (bank0, bank_forks) = Bank::new_with_bank_forks_for_tests(&genesis_config);
+        let tx = system_transaction::transfer(
+            &mint_keypair,
+            &key1.pubkey(),
+            sol_to_lamports(1.),
+            bank0.last_blockhash(),
+        );
+        bank0.process_transaction(&tx).unwrap();
+        while !bank0.is_complete() {
+            bank0.register_unique_tick();
+        }
+
+        let slot = 1;
+        let bank1 =
+            new_bank_from_parent_with_bank_forks(bank_forks.as_ref(), bank0, &collector, slot);
+        while !bank1.is_complete() {
+            bank1.register_unique_tick();
+        }
+        bank1.squash();
+        assert_eq!(bank1.get_signature_status(&tx.signatures[0]), Some(Ok(())));
+
+        let (_tmp_dir, accounts_dir) = create_tmp_accounts_dir_for_tests();
+        let bank_snapshots_dir = tempfile::TempDir::new().unwrap();
+        let full_snapshot_archives_dir = tempfile::TempDir::new().unwrap();
+        let incremental_snapshot_archives_dir = tempfile::TempDir::new().unwrap();
+        let full_snapshot_archive_info = bank_to_full_snapshot_archive(
+            bank_snapshots_dir.path(),
+            &bank1,
+            None,
+            full_snapshot_archives_dir.path(),
+            incremental_snapshot_archives_dir.path(),
+            ArchiveFormat::Tar,
+            snapshot_utils::DEFAULT_MAX_FULL_SNAPSHOT_ARCHIVES_TO_RETAIN,
+            snapshot_utils::DEFAULT_MAX_INCREMENTAL_SNAPSHOT_ARCHIVES_TO_RETAIN,
+        )
+        .unwrap();
+
+        let (roundtrip_bank, _) = bank_from_snapshot_archives(
+            &[accounts_dir],
+            bank_snapshots_dir.path(),
+            &full_snapshot_archive_info,
+            None,
+            &genesis_config,
+            &RuntimeConfig::default(),
+            None,
+            None,
+            AccountSecondaryIndexes::default(),
+            None,
+            AccountShrinkThreshold::default(),
+            false,
+            false,
+            false,
+            false,
+            Some(ACCOUNTS_DB_CONFIG_FOR_TESTING),
+            None,
+            Arc::default(),
+        )
+        .unwrap();
+        roundtrip_bank.wait_for_initial_accounts_hash_verification_completed_for_tests();
+        assert_eq!(*bank1, roundtrip_bank);
+
+        // The transaction's blockhash is still valid, but its signature is in the restored status
+        // cache.
+        let roundtrip_bank_forks = BankForks::new_rw_arc(roundtrip_bank);
+        let roundtrip_bank = roundtrip_bank_forks.read().unwrap().root_bank();
+        let bank2 = new_bank_from_parent_with_bank_forks(
+            roundtrip_bank_forks.as_ref(),
+            roundtrip_bank,
+            &collector,
+            slot + 1,
+        );
+        assert!(bank2.is_blockhash_valid(&tx.message.recent_blockhash));
+        assert_eq!(
+            bank2.process_transaction(&tx),
+            Err(TransactionError::AlreadyProcessed)
+        );
+    }
+
     /// Test roundtrip of bank to snapshots, then back again, with incremental snapshots.  In this
     /// version, build up a few slots and take a full snapshot.  Continue on a few more slots and
     /// take an incremental snapshot.  Rebuild the bank from both the incremental snapshot and full
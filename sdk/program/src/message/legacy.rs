@@ -16,7 +16,10 @@ use {
         bpf_loader, bpf_loader_deprecated, bpf_loader_upgradeable,
         hash::Hash,
         instruction::{CompiledInstruction, Instruction},
-        message::{compiled_keys::CompiledKeys, MessageHeader},
+        message::{
+            compiled_keys::CompiledKeys, deserialize_account_keys, deserialize_instructions,
+            MessageHeader,
+        },
         pubkey::Pubkey,
         sanitize::{Sanitize, SanitizeError},
         short_vec, system_instruction, system_program, sysvar, wasm_bindgen,
@@ -115,7 +118,10 @@ pub struct Message {
 
     /// All the account keys used by this transaction.
     #[wasm_bindgen(skip)]
-    #[serde(with = "short_vec")]
+    #[serde(
+        serialize_with = "short_vec::serialize",
+        deserialize_with = "deserialize_account_keys"
+    )]
     pub account_keys: Vec<Pubkey>,
 
     /// The id of a recent ledger entry.
@@ -124,7 +130,10 @@ pub struct Message {
     /// Programs that will be executed in sequence and committed in one atomic transaction if all
     /// succeed.
     #[wasm_bindgen(skip)]
-    #[serde(with = "short_vec")]
+    #[serde(
+        serialize_with = "short_vec::serialize",
+        deserialize_with = "deserialize_instructions"
+    )]
     pub instructions: Vec<CompiledInstruction>,
 }
 
@@ -641,7 +650,12 @@ mod tests {
     #![allow(deprecated)]
     use {
         super::*,
-        crate::{hash, instruction::AccountMeta, message::MESSAGE_HEADER_LENGTH},
+        crate::{
+            hash,
+            instruction::AccountMeta,
+            message::{MAX_TRANSACTION_SIZE, MESSAGE_HEADER_LENGTH},
+            pubkey::PUBKEY_BYTES,
+        },
         std::collections::HashSet,
     };
 
@@ -900,4 +914,33 @@ mod tests {
             Hash::from_str("7VWCF4quo2CcWQFNUayZiorxpiR5ix8YzLebrXKf3fMF").unwrap()
         )
     }
+
+    #[test]
+    fn test_message_deserialize_max_len() {
+        let max_account_keys = MAX_TRANSACTION_SIZE / PUBKEY_BYTES;
+        let message = Message {
+            account_keys: vec![Pubkey::new_unique(); max_account_keys],
+            ..Message::default()
+        };
+        let bytes = bincode::serialize(&message).unwrap();
+        assert_eq!(bincode::deserialize::<Message>(&bytes).unwrap(), message);
+
+        // more account keys than fit in a packet are rejected
+        let message = Message {
+            account_keys: vec![Pubkey::new_unique(); max_account_keys + 1],
+            ..Message::default()
+        };
+        let bytes = bincode::serialize(&message).unwrap();
+        assert!(bincode::deserialize::<Message>(&bytes).is_err());
+
+        let message = Message {
+            instructions: vec![
+                CompiledInstruction::new_from_raw_parts(0, vec![], vec![]);
+                MAX_TRANSACTION_SIZE / 3 + 1
+            ],
+            ..Message::default()
+        };
+        let bytes = bincode::serialize(&message).unwrap();
+        assert!(bincode::deserialize::<Message>(&bytes).is_err());
+    }
 }
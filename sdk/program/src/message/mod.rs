@@ -58,6 +58,34 @@ pub use {compiled_keys::CompileError, legacy::Message};
 /// The length of a message header in bytes.
 pub const MESSAGE_HEADER_LENGTH: usize = 3;
 
+/// The maximum size of a serialized transaction, which has to fit in a single
+/// packet; the same as `solana_sdk::packet::PACKET_DATA_SIZE`.
+pub(crate) const MAX_TRANSACTION_SIZE: usize = 1280 - 40 - 8;
+
+// Deserializers for the short_vec fields of messages, which reject counts
+// that could not fit in a transaction before decoding any element.
+pub(crate) fn deserialize_account_keys<'de, D>(
+    deserializer: D,
+) -> Result<Vec<crate::pubkey::Pubkey>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let max_len = MAX_TRANSACTION_SIZE / crate::pubkey::PUBKEY_BYTES;
+    crate::short_vec::deserialize_with_max_len(deserializer, max_len)
+}
+
+pub(crate) fn deserialize_instructions<'de, D>(
+    deserializer: D,
+) -> Result<Vec<crate::instruction::CompiledInstruction>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    // A compiled instruction takes at least 3 bytes: the program id index and
+    // the lengths of its accounts and data.
+    let max_len = MAX_TRANSACTION_SIZE / 3;
+    crate::short_vec::deserialize_with_max_len(deserializer, max_len)
+}
+
 /// Describes the organization of a `Message`'s account keys.
 ///
 /// Every [`Instruction`] specifies which accounts it may reference, or
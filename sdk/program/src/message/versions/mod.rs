@@ -2,7 +2,10 @@ use {
     crate::{
         hash::Hash,
         instruction::CompiledInstruction,
-        message::{legacy::Message as LegacyMessage, v0::MessageAddressTableLookup, MessageHeader},
+        message::{
+            deserialize_account_keys, deserialize_instructions, legacy::Message as LegacyMessage,
+            v0::MessageAddressTableLookup, MessageHeader,
+        },
         pubkey::Pubkey,
         sanitize::{Sanitize, SanitizeError},
         short_vec,
@@ -249,10 +252,16 @@ impl<'de> Deserialize<'de> for VersionedMessage {
                         struct RemainingLegacyMessage {
                             pub num_readonly_signed_accounts: u8,
                             pub num_readonly_unsigned_accounts: u8,
-                            #[serde(with = "short_vec")]
+                            #[serde(
+                                serialize_with = "short_vec::serialize",
+                                deserialize_with = "deserialize_account_keys"
+                            )]
                             pub account_keys: Vec<Pubkey>,
                             pub recent_blockhash: Hash,
-                            #[serde(with = "short_vec")]
+                            #[serde(
+                                serialize_with = "short_vec::serialize",
+                                deserialize_with = "deserialize_instructions"
+                            )]
                             pub instructions: Vec<CompiledInstruction>,
                         }
 
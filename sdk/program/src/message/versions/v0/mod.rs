@@ -16,10 +16,11 @@ use crate::{
     instruction::{CompiledInstruction, Instruction},
     message::{
         compiled_keys::{CompileError, CompiledKeys},
+        deserialize_account_keys, deserialize_instructions,
         legacy::is_builtin_key_or_sysvar,
-        AccountKeys, MessageHeader, MESSAGE_VERSION_PREFIX,
+        AccountKeys, MessageHeader, MAX_TRANSACTION_SIZE, MESSAGE_VERSION_PREFIX,
     },
-    pubkey::Pubkey,
+    pubkey::{Pubkey, PUBKEY_BYTES},
     sanitize::SanitizeError,
     short_vec,
 };
@@ -59,7 +60,10 @@ pub struct Message {
     pub header: MessageHeader,
 
     /// List of accounts loaded by this transaction.
-    #[serde(with = "short_vec")]
+    #[serde(
+        serialize_with = "short_vec::serialize",
+        deserialize_with = "deserialize_account_keys"
+    )]
     pub account_keys: Vec<Pubkey>,
 
     /// The blockhash of a recent block.
@@ -78,15 +82,33 @@ pub struct Message {
     ///   1) message `account_keys`
     ///   2) ordered list of keys loaded from `writable` lookup table indexes
     ///   3) ordered list of keys loaded from `readable` lookup table indexes
-    #[serde(with = "short_vec")]
+    #[serde(
+        serialize_with = "short_vec::serialize",
+        deserialize_with = "deserialize_instructions"
+    )]
     pub instructions: Vec<CompiledInstruction>,
 
     /// List of address table lookups used to load additional accounts
     /// for this transaction.
-    #[serde(with = "short_vec")]
+    #[serde(
+        serialize_with = "short_vec::serialize",
+        deserialize_with = "deserialize_address_table_lookups"
+    )]
     pub address_table_lookups: Vec<MessageAddressTableLookup>,
 }
 
+fn deserialize_address_table_lookups<'de, D>(
+    deserializer: D,
+) -> Result<Vec<MessageAddressTableLookup>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    // A lookup takes at least the table address and the lengths of its
+    // writable and readonly indexes.
+    let max_len = MAX_TRANSACTION_SIZE / (PUBKEY_BYTES + 2);
+    short_vec::deserialize_with_max_len(deserializer, max_len)
+}
+
 impl Message {
     /// Sanitize message fields and compiled instruction indexes
     pub fn sanitize(&self) -> Result<(), SanitizeError> {
@@ -7,9 +7,14 @@ use {
         ser::{self, SerializeTuple, Serializer},
         Deserialize, Serialize,
     },
-    std::{convert::TryFrom, fmt, marker::PhantomData},
+    std::{convert::TryFrom, fmt, marker::PhantomData, mem},
 };
 
+/// Upper bound on the memory reserved up front when deserializing a
+/// ShortVec. The length prefix is untrusted input, so anything beyond this
+/// is only allocated as elements are actually decoded.
+const MAX_PREALLOCATED_BYTES: usize = 4096;
+
 /// Same as u16, but serialized with 1 to 3 bytes. If the value is above
 /// 0x7f, the top bit is set and the remaining value is stored in the next
 /// bytes. Each byte follows the same pattern until the 3rd byte. The 3rd
@@ -183,6 +188,7 @@ pub fn serialize<S: Serializer, T: Serialize>(
 }
 
 struct ShortVecVisitor<T> {
+    max_len: usize,
     _t: PhantomData<T>,
 }
 
@@ -204,8 +210,15 @@ where
             .next_element()?
             .ok_or_else(|| de::Error::invalid_length(0, &self))?;
         let len = short_len.0 as usize;
+        if len > self.max_len {
+            return Err(de::Error::custom(format_args!(
+                "length {len} exceeds the maximum of {}",
+                self.max_len
+            )));
+        }
 
-        let mut result = Vec::with_capacity(len);
+        let max_preallocated = MAX_PREALLOCATED_BYTES / mem::size_of::<T>().max(1);
+        let mut result = Vec::with_capacity(len.min(max_preallocated));
         for i in 0..len {
             let elem = seq
                 .next_element()?
@@ -226,7 +239,23 @@ where
     D: Deserializer<'de>,
     T: Deserialize<'de>,
 {
-    let visitor = ShortVecVisitor { _t: PhantomData };
+    deserialize_with_max_len(deserializer, std::u16::MAX as usize)
+}
+
+/// Like [`deserialize`], but fails without decoding any elements if the
+/// encoded length is larger than `max_len`.
+pub fn deserialize_with_max_len<'de, D, T>(
+    deserializer: D,
+    max_len: usize,
+) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+{
+    let visitor = ShortVecVisitor {
+        max_len,
+        _t: PhantomData,
+    };
     deserializer.deserialize_tuple(std::usize::MAX, visitor)
 }
 
@@ -384,4 +413,51 @@ mod tests {
         ];
         assert!(deserialize::<ShortVec<u8>>(&bytes).is_err());
     }
+
+    #[test]
+    fn test_short_vec_truncated() {
+        // A length prefix of u16::MAX followed by a single element must fail
+        // cleanly rather than trusting the prefix.
+        let mut bytes = encode_len(u16::MAX);
+        bytes.extend_from_slice(&[0u8; 32]);
+        assert!(deserialize::<ShortVec<[u8; 32]>>(&bytes).is_err());
+
+        // Every truncation of a valid encoding must be rejected.
+        let bytes = serialize(&ShortVec(vec![7u16; 200])).unwrap();
+        for end in 0..bytes.len() {
+            assert!(deserialize::<ShortVec<u16>>(&bytes[..end]).is_err());
+        }
+        assert_eq!(deserialize::<ShortVec<u16>>(&bytes).unwrap().0, [7; 200]);
+    }
+
+    #[test]
+    fn test_short_vec_roundtrip() {
+        for len in (0..=u16::MAX)
+            .step_by(251)
+            .chain([0x7f, 0x80, 0x3fff, 0x4000])
+        {
+            let vec: Vec<u8> = (0..len).map(|i| i as u8).collect();
+            let bytes = serialize(&ShortVec(vec.clone())).unwrap();
+            assert_eq!(bytes.len(), encode_len(len).len() + vec.len());
+            assert_eq!(deserialize::<ShortVec<u8>>(&bytes).unwrap().0, vec);
+        }
+    }
+
+    #[test]
+    fn test_short_vec_max_len() {
+        #[derive(Debug, Deserialize)]
+        struct Bounded(#[serde(deserialize_with = "deserialize_bounded")] Vec<u8>);
+        fn deserialize_bounded<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<Vec<u8>, D::Error> {
+            deserialize_with_max_len(deserializer, 3)
+        }
+
+        let bytes = serialize(&ShortVec(vec![1u8, 2, 3])).unwrap();
+        assert_eq!(deserialize::<Bounded>(&bytes).unwrap().0, [1, 2, 3]);
+        let bytes = serialize(&ShortVec(vec![1u8, 2, 3, 4])).unwrap();
+        assert!(deserialize::<Bounded>(&bytes).is_err());
+        // the length check happens before any element is read
+        assert!(deserialize::<Bounded>(&encode_len(u16::MAX)).is_err());
+    }
 }
@@ -293,6 +293,19 @@ pub enum StakeInstruction {
     ///   4. `[SIGNER]` Stake authority
     ///
     Redelegate,
+
+    /// Replace the lockup custodian, leaving the lockup's unix timestamp and epoch unchanged
+    ///
+    /// Both the current lockup custodian and the withdraw authority must sign, whether or not
+    /// the lockup is in force.
+    ///
+    /// # Account references
+    ///   0. `[WRITE]` Initialized stake account
+    ///   1. `[SIGNER]` Current lockup custodian
+    ///   2. `[SIGNER]` Withdraw authority
+    ///
+    /// The Pubkey parameter is the new lockup custodian.
+    RotateCustodian(Pubkey),
 }
 
 #[derive(Default, Debug, Serialize, Deserialize, PartialEq, Eq, Clone, Copy)]
@@ -750,6 +763,24 @@ pub fn set_lockup_checked(
     )
 }
 
+pub fn rotate_custodian(
+    stake_pubkey: &Pubkey,
+    custodian_pubkey: &Pubkey,
+    withdrawer_pubkey: &Pubkey,
+    new_custodian_pubkey: &Pubkey,
+) -> Instruction {
+    let account_metas = vec![
+        AccountMeta::new(*stake_pubkey, false),
+        AccountMeta::new_readonly(*custodian_pubkey, true),
+        AccountMeta::new_readonly(*withdrawer_pubkey, true),
+    ];
+    Instruction::new_with_bincode(
+        id(),
+        &StakeInstruction::RotateCustodian(*new_custodian_pubkey),
+        account_metas,
+    )
+}
+
 pub fn get_minimum_delegation() -> Instruction {
     Instruction::new_with_bincode(
         id(),
@@ -515,6 +515,22 @@ impl Meta {
         Ok(())
     }
 
+    /// Replace the lockup custodian; requires both the current custodian and
+    /// the withdraw authority to sign.
+    pub fn rotate_custodian(
+        &mut self,
+        new_custodian: &Pubkey,
+        signers: &HashSet<Pubkey>,
+    ) -> Result<(), InstructionError> {
+        if !signers.contains(&self.lockup.custodian)
+            || !signers.contains(&self.authorized.withdrawer)
+        {
+            return Err(InstructionError::MissingRequiredSignature);
+        }
+        self.lockup.custodian = *new_custodian;
+        Ok(())
+    }
+
     pub fn auto(authorized: &Pubkey) -> Self {
         Self {
             authorized: Authorized::auto(authorized),
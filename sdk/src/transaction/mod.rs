@@ -117,6 +117,7 @@ use {
         instruction::{CompiledInstruction, Instruction},
         message::Message,
         nonce::NONCED_TX_MARKER_IX_INDEX,
+        packet::PACKET_DATA_SIZE,
         precompiles::verify_if_precompile,
         program_utils::limited_deserialize,
         pubkey::Pubkey,
@@ -181,7 +182,10 @@ pub struct Transaction {
     /// [`num_required_signatures`]: crate::message::MessageHeader::num_required_signatures
     // NOTE: Serialization-related changes must be paired with the direct read at sigverify.
     #[wasm_bindgen(skip)]
-    #[serde(with = "short_vec")]
+    #[serde(
+        serialize_with = "short_vec::serialize",
+        deserialize_with = "deserialize_signatures"
+    )]
     pub signatures: Vec<Signature>,
 
     /// The message to sign.
@@ -189,6 +193,19 @@ pub struct Transaction {
     pub message: Message,
 }
 
+// Rejects signature counts that could not fit in a packet before decoding
+// any of the signatures.
+#[allow(clippy::arithmetic_side_effects)]
+pub(crate) fn deserialize_signatures<'de, D>(
+    deserializer: D,
+) -> result::Result<Vec<Signature>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let max_len = PACKET_DATA_SIZE / std::mem::size_of::<Signature>();
+    short_vec::deserialize_with_max_len(deserializer, max_len)
+}
+
 impl Sanitize for Transaction {
     fn sanitize(&self) -> std::result::Result<(), SanitizeError> {
         if self.message.header.num_required_signatures as usize > self.signatures.len() {
@@ -1295,6 +1312,15 @@ mod tests {
         assert_eq!(tx, deser);
     }
 
+    #[test]
+    fn test_transaction_deserialize_too_many_signatures() {
+        let mut tx = create_sample_transaction();
+        tx.signatures = vec![Signature::default(); PACKET_DATA_SIZE / size_of::<Signature>() + 1];
+        let ser = serialize(&tx).unwrap();
+        assert!(deserialize::<Transaction>(&ser).is_err());
+        assert!(deserialize::<VersionedTransaction>(&ser).is_err());
+    }
+
     /// Detect changes to the serialized size of payment transactions, which affects TPS.
     #[test]
     fn test_transaction_minimum_serialized_size() {
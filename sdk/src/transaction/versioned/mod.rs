@@ -11,7 +11,7 @@ use {
         signature::Signature,
         signer::SignerError,
         signers::Signers,
-        transaction::{Result, Transaction, TransactionError},
+        transaction::{deserialize_signatures, Result, Transaction, TransactionError},
     },
     serde::Serialize,
     std::cmp::Ordering,
@@ -50,7 +50,10 @@ impl TransactionVersion {
 #[derive(Debug, PartialEq, Default, Eq, Clone, Serialize, Deserialize, AbiExample)]
 pub struct VersionedTransaction {
     /// List of signatures
-    #[serde(with = "short_vec")]
+    #[serde(
+        serialize_with = "short_vec::serialize",
+        deserialize_with = "deserialize_signatures"
+    )]
     pub signatures: Vec<Signature>,
     /// Message to sign.
     pub message: VersionedMessage,
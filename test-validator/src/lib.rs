@@ -295,6 +295,37 @@ impl TestValidatorGenesis {
         self
     }
 
+    /// Add an initialized SPL Token mint to the test environment
+    pub fn add_spl_token_mint(
+        &mut self,
+        address: Pubkey,
+        mint_authority: &Pubkey,
+        supply: u64,
+        decimals: u8,
+    ) -> &mut Self {
+        let account = solana_program_test::programs::spl_token_mint(
+            &self.rent,
+            mint_authority,
+            supply,
+            decimals,
+        );
+        self.add_account(address, account)
+    }
+
+    /// Add an initialized SPL Token account holding `amount` tokens of `mint` to the test
+    /// environment
+    pub fn add_spl_token_account(
+        &mut self,
+        address: Pubkey,
+        mint: &Pubkey,
+        owner: &Pubkey,
+        amount: u64,
+    ) -> &mut Self {
+        let account =
+            solana_program_test::programs::spl_token_account(&self.rent, mint, owner, amount);
+        self.add_account(address, account)
+    }
+
     pub fn add_accounts<T>(&mut self, accounts: T) -> &mut Self
     where
         T: IntoIterator<Item = (Pubkey, AccountSharedData)>,
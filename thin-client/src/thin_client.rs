@@ -4,16 +4,23 @@
 //! unstable and may change in future releases.
 
 use {
+    futures_util::StreamExt,
     log::*,
     rayon::iter::{IntoParallelIterator, ParallelIterator},
+    solana_account_decoder::UiAccountEncoding,
     solana_connection_cache::{
         client_connection::ClientConnection,
         connection_cache::{
             ConnectionCache, ConnectionManager, ConnectionPool, NewConnectionConfig,
         },
     },
+    solana_pubsub_client::nonblocking::pubsub_client::PubsubClient,
     solana_rpc_client::rpc_client::RpcClient,
-    solana_rpc_client_api::{config::RpcProgramAccountsConfig, response::Response},
+    solana_rpc_client_api::{
+        config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        request::MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS,
+        response::Response,
+    },
     solana_sdk::{
         account::Account,
         client::{AsyncClient, Client, SyncClient},
@@ -29,7 +36,7 @@ use {
         signers::Signers,
         system_instruction,
         timing::duration_as_ms,
-        transaction::{self, Transaction, VersionedTransaction},
+        transaction::{self, Transaction, TransactionError, VersionedTransaction},
         transport::Result as TransportResult,
     },
     std::{
@@ -37,12 +44,91 @@ use {
         net::SocketAddr,
         sync::{
             atomic::{AtomicBool, AtomicUsize, Ordering},
-            Arc, RwLock,
+            Arc, Mutex, OnceLock, RwLock,
         },
+        thread::sleep,
         time::{Duration, Instant},
     },
 };
 
+/// Maximum number of transactions handed to the TPU connection at once by
+/// [`ThinClient::send_batch`].
+pub const SEND_BATCH_SIZE: usize = 64;
+
+const BATCH_CONFIRMATION_POLL_INTERVAL: Duration = Duration::from_millis(250);
+const WATCH_ACCOUNT_POLL_INTERVAL: Duration = Duration::from_millis(250);
+
+/// How long [`ThinClient::wait_for_balance_with_commitment`] waits for the
+/// expected balance.
+pub const WAIT_FOR_BALANCE_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// How [`ThinClient::watch_account`] observed the change or timeout that
+/// ended the watch.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccountWatchSource {
+    /// Notifications streamed over the websocket endpoint.
+    Pubsub,
+    /// Polling over RPC.
+    Polling,
+}
+
+/// Websocket connection shared by [`ThinClient::watch_account`] calls, and
+/// reopened by the next call once it fails.
+struct AccountPubsub {
+    url: String,
+    // The blocking pubsub client cannot be shut down while it waits for a
+    // notification, so the nonblocking one is driven by a runtime of our own,
+    // which also answers pings between watches. Started by the first watch.
+    runtime: OnceLock<tokio::runtime::Runtime>,
+    client: Mutex<Option<Arc<PubsubClient>>>,
+}
+
+impl AccountPubsub {
+    fn new(url: &str) -> Self {
+        Self {
+            url: url.to_string(),
+            runtime: OnceLock::new(),
+            client: Mutex::default(),
+        }
+    }
+
+    fn runtime(&self) -> io::Result<&tokio::runtime::Runtime> {
+        if let Some(runtime) = self.runtime.get() {
+            return Ok(runtime);
+        }
+        let runtime = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("solThinPubsub")
+            .enable_all()
+            .build()?;
+        // A concurrent first watch may have won the race, in which case this
+        // runtime is dropped unused.
+        Ok(self.runtime.get_or_init(|| runtime))
+    }
+
+    fn client(&self, runtime: &tokio::runtime::Runtime) -> Option<Arc<PubsubClient>> {
+        let mut client = self.client.lock().unwrap();
+        if client.is_none() {
+            match runtime.block_on(PubsubClient::new(&self.url)) {
+                Ok(pubsub_client) => *client = Some(Arc::new(pubsub_client)),
+                Err(err) => warn!("failed to connect to {}, polling instead: {err}", self.url),
+            }
+        }
+        client.clone()
+    }
+
+    // Drops a failed connection, unless another call already replaced it.
+    fn reset(&self, failed: &Arc<PubsubClient>) {
+        let mut client = self.client.lock().unwrap();
+        if client
+            .as_ref()
+            .is_some_and(|client| Arc::ptr_eq(client, failed))
+        {
+            *client = None;
+        }
+    }
+}
+
 struct ClientOptimizer {
     cur_index: AtomicUsize,
     experiment_index: AtomicUsize,
@@ -119,6 +205,7 @@ pub struct ThinClient<
 > {
     rpc_clients: Vec<RpcClient>,
     tpu_addrs: Vec<SocketAddr>,
+    pubsub: Option<AccountPubsub>,
     optimizer: ClientOptimizer,
     connection_cache: Arc<ConnectionCache<P, M, C>>,
 }
@@ -159,6 +246,7 @@ where
         Self {
             rpc_clients: vec![rpc_client],
             tpu_addrs: vec![tpu_addr],
+            pubsub: None,
             optimizer: ClientOptimizer::new(0),
             connection_cache,
         }
@@ -177,11 +265,19 @@ where
         Self {
             rpc_clients,
             tpu_addrs,
+            pubsub: None,
             optimizer,
             connection_cache,
         }
     }
 
+    /// Use the websocket endpoint at `pubsub_url` to stream account changes
+    /// in [`Self::watch_account`] instead of polling over RPC. Nothing is
+    /// started until the first watch.
+    pub fn set_pubsub_url(&mut self, pubsub_url: &str) {
+        self.pubsub = Some(AccountPubsub::new(pubsub_url));
+    }
+
     fn tpu_addr(&self) -> &SocketAddr {
         &self.tpu_addrs[self.optimizer.best()]
     }
@@ -260,6 +356,72 @@ where
         .into())
     }
 
+    /// Send `transactions` to the TPU in chunks of [`SEND_BATCH_SIZE`] without
+    /// waiting for any of them to be confirmed, and return their signatures in
+    /// the same order. Use [`Self::poll_for_batch_confirmation`] to wait for
+    /// the whole batch. Nothing is sent if any of the transactions is not
+    /// fully signed.
+    ///
+    /// If sending a chunk fails, the error is returned and the remaining
+    /// chunks are not sent, but the earlier chunks may already have reached
+    /// the TPU. A caller that needs to know which transactions landed should
+    /// poll their signatures with [`Self::poll_for_batch_confirmation`].
+    pub fn send_batch(&self, transactions: &[Transaction]) -> TransportResult<Vec<Signature>> {
+        let signatures = transactions
+            .iter()
+            .map(|tx| match tx.signatures.first() {
+                Some(signature) if tx.is_signed() => Ok(*signature),
+                _ => Err(TransactionError::SignatureFailure.into()),
+            })
+            .collect::<TransportResult<Vec<_>>>()?;
+        for chunk in transactions.chunks(SEND_BATCH_SIZE) {
+            self.async_send_batch(chunk.to_vec())?;
+        }
+        Ok(signatures)
+    }
+
+    /// Poll until each of `signatures` has reached `commitment_config`, or
+    /// until `timeout` elapses. Returns the status of every signature in the
+    /// same order, `None` for the ones that were not confirmed in time. Failed
+    /// status requests are logged and retried until `timeout`.
+    pub fn poll_for_batch_confirmation(
+        &self,
+        signatures: &[Signature],
+        commitment_config: CommitmentConfig,
+        timeout: Duration,
+    ) -> TransportResult<Vec<Option<transaction::Result<()>>>> {
+        let now = Instant::now();
+        let mut statuses = vec![None; signatures.len()];
+        loop {
+            let pending: Vec<_> = statuses
+                .iter()
+                .enumerate()
+                .filter_map(|(i, status)| status.is_none().then_some(i))
+                .collect();
+            for indexes in pending.chunks(MAX_GET_SIGNATURE_STATUSES_QUERY_ITEMS) {
+                let query: Vec<_> = indexes.iter().map(|i| signatures[*i]).collect();
+                let response = match self.rpc_client().get_signature_statuses(&query) {
+                    Ok(response) => response,
+                    Err(err) => {
+                        debug!("failed to get signature statuses, retrying: {err}");
+                        continue;
+                    }
+                };
+                for (i, status) in indexes.iter().zip(response.value) {
+                    if let Some(status) = status {
+                        if status.satisfies_commitment(commitment_config) {
+                            statuses[*i] = Some(status.status);
+                        }
+                    }
+                }
+            }
+            if statuses.iter().all(Option::is_some) || now.elapsed() >= timeout {
+                return Ok(statuses);
+            }
+            sleep(BATCH_CONFIRMATION_POLL_INTERVAL);
+        }
+    }
+
     pub fn poll_get_balance(&self, pubkey: &Pubkey) -> TransportResult<u64> {
         self.poll_get_balance_with_commitment(pubkey, CommitmentConfig::default())
     }
@@ -275,11 +437,7 @@ where
     }
 
     pub fn wait_for_balance(&self, pubkey: &Pubkey, expected_balance: Option<u64>) -> Option<u64> {
-        self.rpc_client().wait_for_balance_with_commitment(
-            pubkey,
-            expected_balance,
-            CommitmentConfig::default(),
-        )
+        self.wait_for_balance_with_commitment(pubkey, expected_balance, CommitmentConfig::default())
     }
 
     pub fn get_program_accounts_with_config(
@@ -292,17 +450,159 @@ where
             .map_err(|e| e.into())
     }
 
+    /// Waits up to [`WAIT_FOR_BALANCE_TIMEOUT`] for the balance of `pubkey`
+    /// to reach `expected_balance`, and returns `None` if it does not. Without
+    /// an expected balance, returns the current balance.
     pub fn wait_for_balance_with_commitment(
         &self,
         pubkey: &Pubkey,
         expected_balance: Option<u64>,
         commitment_config: CommitmentConfig,
     ) -> Option<u64> {
-        self.rpc_client().wait_for_balance_with_commitment(
+        let Some(expected_balance) = expected_balance else {
+            return self
+                .poll_get_balance_with_commitment(pubkey, commitment_config)
+                .ok();
+        };
+        let mut reached = false;
+        self.watch_account(
             pubkey,
-            expected_balance,
             commitment_config,
+            WAIT_FOR_BALANCE_TIMEOUT,
+            &mut |account| {
+                reached = account.map_or(0, |account| account.lamports) == expected_balance;
+                !reached
+            },
         )
+        .ok()?;
+        reached.then_some(expected_balance)
+    }
+
+    /// Calls `on_change` with the current state of `pubkey`, then again every
+    /// time the account is created, modified or closed, until `on_change`
+    /// returns `false` or `timeout` elapses.
+    ///
+    /// Changes are streamed from the websocket endpoint given to
+    /// [`Self::set_pubsub_url`]. Without one, or if the subscription fails,
+    /// the account is polled over RPC instead. Failed polls are retried until
+    /// `timeout`, and only the last error is returned. Fails right away if
+    /// the runtime driving the websocket connection cannot be started.
+    pub fn watch_account(
+        &self,
+        pubkey: &Pubkey,
+        commitment_config: CommitmentConfig,
+        timeout: Duration,
+        on_change: &mut dyn FnMut(Option<&Account>) -> bool,
+    ) -> TransportResult<AccountWatchSource> {
+        let now = Instant::now();
+        let mut last_seen = None;
+        let mut notify = |account: Option<Account>| {
+            if last_seen.as_ref() == Some(&account) {
+                return true;
+            }
+            let keep_watching = on_change(account.as_ref());
+            last_seen = Some(account);
+            keep_watching
+        };
+        if let Some(pubsub) = &self.pubsub {
+            if !self.stream_account(pubsub, pubkey, commitment_config, now, timeout, &mut notify)? {
+                return Ok(AccountWatchSource::Pubsub);
+            }
+        }
+        loop {
+            match self.get_account_with_commitment(pubkey, commitment_config) {
+                Ok(account) => {
+                    if !notify(account) {
+                        break;
+                    }
+                }
+                Err(err) if now.elapsed() < timeout => {
+                    debug!("failed to get account {pubkey}, retrying: {err}");
+                }
+                Err(err) => return Err(err),
+            }
+            if now.elapsed() >= timeout {
+                break;
+            }
+            sleep(WATCH_ACCOUNT_POLL_INTERVAL);
+        }
+        Ok(AccountWatchSource::Polling)
+    }
+
+    /// Feeds account notifications from `pubsub` to `notify` for
+    /// [`Self::watch_account`]. Returns `true` if the subscription could not
+    /// be set up or failed before the watch ended, in which case the caller
+    /// keeps watching by polling.
+    fn stream_account(
+        &self,
+        pubsub: &AccountPubsub,
+        pubkey: &Pubkey,
+        commitment_config: CommitmentConfig,
+        start: Instant,
+        timeout: Duration,
+        notify: &mut dyn FnMut(Option<Account>) -> bool,
+    ) -> io::Result<bool> {
+        let runtime = pubsub.runtime()?;
+        let Some(pubsub_client) = pubsub.client(runtime) else {
+            return Ok(true);
+        };
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(commitment_config),
+            ..RpcAccountInfoConfig::default()
+        };
+        let (mut notifications, unsubscribe) =
+            match runtime.block_on(pubsub_client.account_subscribe(pubkey, Some(config))) {
+                Ok(subscription) => subscription,
+                Err(err) => {
+                    warn!(
+                        "failed to subscribe to {pubkey} at {}, polling instead: {err}",
+                        pubsub.url
+                    );
+                    pubsub.reset(&pubsub_client);
+                    return Ok(true);
+                }
+            };
+        // Notifications only carry changes, so read the state the
+        // subscription starts from.
+        let keep_watching = match self.get_account_with_commitment(pubkey, commitment_config) {
+            Ok(account) => {
+                notify(account)
+                    && loop {
+                        let Some(remaining) = timeout.checked_sub(start.elapsed()) else {
+                            break false;
+                        };
+                        // The timer has to be created inside the runtime
+                        let notification = runtime.block_on(async {
+                            tokio::time::timeout(remaining, notifications.next()).await
+                        });
+                        match notification {
+                            Ok(Some(response)) => {
+                                // Closed accounts are reported with no lamports.
+                                let account = response
+                                    .value
+                                    .decode::<Account>()
+                                    .filter(|account| account.lamports > 0);
+                                if !notify(account) {
+                                    break false;
+                                }
+                            }
+                            Ok(None) => {
+                                warn!("subscription to {} closed, polling instead", pubsub.url);
+                                pubsub.reset(&pubsub_client);
+                                break true;
+                            }
+                            Err(_) => break false,
+                        }
+                    }
+            }
+            Err(err) => {
+                debug!("failed to get account {pubkey}, polling instead: {err}");
+                true
+            }
+        };
+        runtime.block_on(unsubscribe());
+        Ok(keep_watching)
     }
 
     pub fn poll_for_signature_with_commitment(
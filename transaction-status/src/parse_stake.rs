@@ -297,6 +297,18 @@ pub fn parse_stake(
                 }),
             })
         }
+        StakeInstruction::RotateCustodian(new_custodian) => {
+            check_num_stake_accounts(&instruction.accounts, 3)?;
+            Ok(ParsedInstructionEnum {
+                instruction_type: "rotateCustodian".to_string(),
+                info: json!({
+                    "stakeAccount": account_keys[instruction.accounts[0] as usize].to_string(),
+                    "custodian": account_keys[instruction.accounts[1] as usize].to_string(),
+                    "withdrawAuthority": account_keys[instruction.accounts[2] as usize].to_string(),
+                    "newCustodian": new_custodian.to_string(),
+                }),
+            })
+        }
     }
 }
 
@@ -950,6 +962,45 @@ mod test {
         assert!(parse_stake(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
     }
 
+    #[test]
+    fn test_parse_stake_rotate_custodian_ix() {
+        let stake_pubkey = Pubkey::new_unique();
+        let custodian_pubkey = Pubkey::new_unique();
+        let withdrawer_pubkey = Pubkey::new_unique();
+        let new_custodian_pubkey = Pubkey::new_unique();
+        let instruction = instruction::rotate_custodian(
+            &stake_pubkey,
+            &custodian_pubkey,
+            &withdrawer_pubkey,
+            &new_custodian_pubkey,
+        );
+        let mut message = Message::new(&[instruction], None);
+        assert_eq!(
+            parse_stake(
+                &message.instructions[0],
+                &AccountKeys::new(&message.account_keys, None)
+            )
+            .unwrap(),
+            ParsedInstructionEnum {
+                instruction_type: "rotateCustodian".to_string(),
+                info: json!({
+                    "stakeAccount": stake_pubkey.to_string(),
+                    "custodian": custodian_pubkey.to_string(),
+                    "withdrawAuthority": withdrawer_pubkey.to_string(),
+                    "newCustodian": new_custodian_pubkey.to_string(),
+                }),
+            }
+        );
+        assert!(parse_stake(
+            &message.instructions[0],
+            &AccountKeys::new(&message.account_keys[0..2], None)
+        )
+        .is_err());
+        let keys = message.account_keys.clone();
+        message.instructions[0].accounts.pop();
+        assert!(parse_stake(&message.instructions[0], &AccountKeys::new(&keys, None)).is_err());
+    }
+
     #[test]
     fn test_parse_stake_create_account_checked_ix() {
         let from_pubkey = Pubkey::new_unique();
@@ -713,17 +713,7 @@ impl AdminRpcImpl {
                     })?;
             }
 
-            for n in post_init.notifies.iter() {
-                if let Err(err) = n.update_key(&identity_keypair) {
-                    error!("Error updating network layer keypair: {err}");
-                }
-            }
-
-            solana_metrics::set_host_id(identity_keypair.pubkey().to_string());
-            post_init
-                .cluster_info
-                .set_keypair(Arc::new(identity_keypair));
-            warn!("Identity set to {}", post_init.cluster_info.id());
+            post_init.set_identity(Arc::new(identity_keypair));
             Ok(())
         })
     }
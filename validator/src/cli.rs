@@ -21,6 +21,7 @@ use {
     },
     solana_core::{
         banking_trace::{DirByteLimit, BANKING_TRACE_DIR_DEFAULT_BYTE_LIMIT},
+        standby_service::DEFAULT_STANDBY_MAX_MISSED_SLOTS,
         validator::{BlockProductionMethod, BlockVerificationMethod},
     },
     solana_faucet::faucet::{self, FAUCET_PORT},
@@ -283,6 +284,18 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                      latest optimistically confirmed slot",
                 ),
         )
+        .arg(
+            Arg::with_name("health_check_max_accounts_write_cache_bytes")
+                .long("health-check-max-accounts-write-cache-bytes")
+                .value_name("BYTES")
+                .takes_value(true)
+                .validator(is_parsable::<u64>)
+                .help(
+                    "Report this validator as unhealthy while the banks it has not rooted and \
+                     flushed yet hold more than this many bytes of account data in the \
+                     accounts write cache",
+                ),
+        )
         .arg(
             Arg::with_name("rpc_faucet_addr")
                 .long("rpc-faucet-address")
@@ -358,10 +371,15 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
         .arg(
             Arg::with_name("tower_storage")
                 .long("tower-storage")
-                .possible_values(&["file", "etcd"])
+                .possible_values(&["file", "shared-file", "etcd"])
                 .default_value(&default_args.tower_storage)
                 .takes_value(true)
-                .help("Where to store the tower"),
+                .help(
+                    "Where to store the tower. shared-file is a --tower directory shared \
+                     with a --standby-identity node, with a lease that fences off the node \
+                     that lost it. Over NFS, it requires NFSv3 or later mounted with \
+                     lookupcache=none,noac",
+                ),
         )
         .arg(
             Arg::with_name("etcd_endpoint")
@@ -424,6 +442,17 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                      [default: ask --entrypoint, or 127.0.0.1 when --entrypoint is not provided]",
                 ),
         )
+        .arg(
+            Arg::with_name("ip_echo_http_port")
+                .long("ip-echo-http-port")
+                .value_name("PORT")
+                .takes_value(true)
+                .validator(port_validator)
+                .help(
+                    "Also serve /health and /public-ip as JSON over HTTP on this port, from the \
+                     same runtime as the ip echo server on the gossip port",
+                ),
+        )
         .arg(
             Arg::with_name("public_tpu_addr")
                 .long("public-tpu-address")
@@ -707,6 +736,43 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                 .takes_value(false)
                 .help("Refuse to start if saved tower state is not found"),
         )
+        .arg(
+            Arg::with_name("standby_identity")
+                .long("standby-identity")
+                .value_name("KEYPAIR")
+                .takes_value(true)
+                .validator(is_keypair)
+                .help(
+                    "Run as a hot spare for the validator with this identity. Once the primary \
+                     stops voting, the node takes over the primary's saved tower and switches \
+                     to its identity. Requires --tower-storage shared-file or etcd, shared \
+                     with the primary, which must use the same --tower-storage: the takeover \
+                     is refused until the primary stores its tower under a lease",
+                ),
+        )
+        .arg(
+            Arg::with_name("standby_max_missed_slots")
+                .long("standby-max-missed-slots")
+                .value_name("SLOTS")
+                .takes_value(true)
+                .validator(is_parsable::<u64>)
+                .default_value(&default_args.standby_max_missed_slots)
+                .help(
+                    "Number of slots without a vote from the primary before a \
+                     --standby-identity node takes over",
+                ),
+        )
+        .arg(
+            Arg::with_name("check_tower_lease")
+                .long("check-tower-lease")
+                .takes_value(false)
+                .help(
+                    "Skip leader slots unless this node holds the lease on its tower, and exit \
+                     once a --standby-identity node takes it over. Set it on the primary of a \
+                     standby; implied by --standby-identity. Requires --tower-storage \
+                     shared-file or etcd",
+                ),
+        )
         .arg(
             Arg::with_name("expected_genesis_hash")
                 .long("expected-genesis-hash")
@@ -1195,6 +1261,53 @@ pub fn app<'a>(version: &'a str, default_args: &'a DefaultArgs) -> App<'a, 'a> {
                 .default_value(&default_args.rpc_max_request_body_size)
                 .help("The maximum request body size accepted by rpc service"),
         )
+        .arg(
+            Arg::with_name("rpc_allow_method")
+                .long("rpc-allow-method")
+                .value_name("METHOD")
+                .takes_value(true)
+                .multiple(true)
+                .help(
+                    "Serve only the given JSON RPC methods and reject calls to any other. \
+                     May be specified multiple times",
+                ),
+        )
+        .arg(
+            Arg::with_name("rpc_disable_method")
+                .long("rpc-disable-method")
+                .value_name("METHOD")
+                .takes_value(true)
+                .multiple(true)
+                .help(
+                    "Reject calls to this JSON RPC method, e.g. getProgramAccounts. \
+                     May be specified multiple times",
+                ),
+        )
+        .arg(
+            Arg::with_name("rpc_method_rate_limit")
+                .long("rpc-method-rate-limit")
+                .value_name("METHOD=REQUESTS_PER_SECOND")
+                .takes_value(true)
+                .multiple(true)
+                .validator(|value| parse_method_rate_limit(&value).map(|_| ()))
+                .help(
+                    "Limit how many calls to this JSON RPC method are served per second. \
+                     The limit applies to each client with --rpc-trusted-proxy, and is \
+                     shared by all clients otherwise. Throttled calls fail with a \
+                     retry-after hint. May be specified multiple times",
+                ),
+        )
+        .arg(
+            Arg::with_name("rpc_trusted_proxy")
+                .long("rpc-trusted-proxy")
+                .takes_value(false)
+                .help(
+                    "The JSON RPC port is only reachable through a reverse proxy that \
+                     appends the client address to the X-Forwarded-For header. Required \
+                     for --rpc-method-rate-limit to apply to each client, since a client \
+                     connecting directly can set the header to any value",
+                ),
+        )
         .arg(
             Arg::with_name("geyser_plugin_config")
                 .long("geyser-plugin-config")
@@ -2140,6 +2253,7 @@ pub struct DefaultArgs {
     pub health_check_slot_distance: String,
     pub tower_storage: String,
     pub etcd_domain_name: String,
+    pub standby_max_missed_slots: String,
     pub send_transaction_service_config: send_transaction_service::Config,
 
     pub rpc_max_multiple_accounts: String,
@@ -2211,6 +2325,7 @@ impl DefaultArgs {
             health_check_slot_distance: "150".to_string(),
             tower_storage: "file".to_string(),
             etcd_domain_name: "localhost".to_string(),
+            standby_max_missed_slots: DEFAULT_STANDBY_MAX_MISSED_SLOTS.to_string(),
             rpc_pubsub_max_active_subscriptions: PubSubConfig::default()
                 .max_active_subscriptions
                 .to_string(),
@@ -2311,6 +2426,21 @@ pub fn port_range_validator(port_range: String) -> Result<(), String> {
     }
 }
 
+/// Parses a `METHOD=REQUESTS_PER_SECOND` rpc rate limit.
+pub fn parse_method_rate_limit(value: &str) -> Result<(String, u64), String> {
+    let (method, rate) = value
+        .split_once('=')
+        .ok_or_else(|| format!("expected METHOD=REQUESTS_PER_SECOND, got {value}"))?;
+    let rate = rate.parse::<u64>().map_err(|e| format!("{e:?}"))?;
+    if rate == 0 {
+        return Err(format!(
+            "{method} cannot be limited to 0 requests per second, \
+             use --rpc-disable-method {method} instead"
+        ));
+    }
+    Ok((method.to_string(), rate))
+}
+
 fn hash_validator(hash: String) -> Result<(), String> {
     Hash::from_str(&hash)
         .map(|_| ())
@@ -20,6 +20,7 @@ use {
     solana_core::{
         banking_trace::DISABLED_BAKING_TRACE_DIR,
         consensus::tower_storage,
+        standby_service::StandbyConfig,
         system_monitor_service::SystemMonitorService,
         tpu::DEFAULT_TPU_COALESCE,
         validator::{
@@ -64,7 +65,7 @@ use {
         admin_rpc_service,
         admin_rpc_service::{load_staked_nodes_overrides, StakedNodesOverrides},
         bootstrap,
-        cli::{app, warn_for_deprecated_arguments, DefaultArgs},
+        cli::{app, parse_method_rate_limit, warn_for_deprecated_arguments, DefaultArgs},
         dashboard::Dashboard,
         ledger_lockfile, lock_ledger, new_spinner_progress_bar, println_name_value,
         redirect_stderr_to_file,
@@ -427,7 +428,10 @@ fn get_cluster_shred_version(entrypoints: &[SocketAddr]) -> Option<u16> {
         index.into_iter().map(|i| &entrypoints[i])
     };
     for entrypoint in entrypoints {
-        match solana_net_utils::get_cluster_shred_version(entrypoint) {
+        // The shred version does not depend on where the request comes from, so
+        // it may be fetched through the proxy configured in the environment
+        let proxy = solana_net_utils::proxy::ProxyConfig::from_env(entrypoint);
+        match solana_net_utils::get_cluster_shred_version_with_proxy(entrypoint, proxy.as_ref()) {
             Err(err) => eprintln!("get_cluster_shred_version failed: {entrypoint}, {err}"),
             Ok(0) => eprintln!("zero shred-version from entrypoint: {entrypoint}"),
             Ok(shred_version) => {
@@ -1132,6 +1136,13 @@ pub fn main() {
 
                 Arc::new(tower_storage::FileTowerStorage::new(tower_path))
             }
+            "shared-file" => {
+                let tower_path = value_t!(matches, "tower", PathBuf)
+                    .ok()
+                    .unwrap_or_else(|| ledger_path.clone());
+
+                Arc::new(tower_storage::SharedFileTowerStorage::new(tower_path))
+            }
             "etcd" => {
                 let endpoints = values_t_or_exit!(matches, "etcd_endpoint", String);
                 let domain_name = value_t_or_exit!(matches, "etcd_domain_name", String);
@@ -1363,6 +1374,12 @@ pub fn main() {
                 "health_check_slot_distance",
                 u64
             ),
+            health_check_max_accounts_write_cache_bytes: value_t!(
+                matches,
+                "health_check_max_accounts_write_cache_bytes",
+                u64
+            )
+            .ok(),
             disable_health_check: false,
             rpc_threads: value_t_or_exit!(matches, "rpc_threads", usize),
             rpc_niceness_adj: value_t_or_exit!(matches, "rpc_niceness_adj", i8),
@@ -1373,6 +1390,23 @@ pub fn main() {
                 "rpc_max_request_body_size",
                 usize
             )),
+            allowed_methods: values_t!(matches, "rpc_allow_method", String)
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+            disabled_methods: values_t!(matches, "rpc_disable_method", String)
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
+            method_rate_limits: matches
+                .values_of("rpc_method_rate_limit")
+                .map(|values| {
+                    values
+                        .map(|value| parse_method_rate_limit(value).unwrap())
+                        .collect()
+                })
+                .unwrap_or_default(),
+            trusted_proxy: matches.is_present("rpc_trusted_proxy"),
         },
         on_start_geyser_plugin_config_files,
         rpc_addrs: value_t!(matches, "rpc_port", u16).ok().map(|rpc_port| {
@@ -1384,6 +1418,9 @@ pub fn main() {
                 // https://github.com/solana-labs/solana/issues/12250
             )
         }),
+        ip_echo_http_addr: value_t!(matches, "ip_echo_http_port", u16)
+            .ok()
+            .map(|port| SocketAddr::new(bind_address, port)),
         pubsub_config: PubSubConfig {
             enable_block_subscription: matches.is_present("rpc_pubsub_enable_block_subscription"),
             enable_vote_subscription: matches.is_present("rpc_pubsub_enable_vote_subscription"),
@@ -1689,6 +1726,26 @@ pub fn main() {
     .unwrap_or_default();
     validator_config.unified_scheduler_handler_threads =
         value_t!(matches, "unified_scheduler_handler_threads", usize).ok();
+    validator_config.standby_config =
+        keypair_of(&matches, "standby_identity").map(|primary_identity| {
+            if primary_identity.pubkey() == identity_keypair.pubkey() {
+                eprintln!("--standby-identity must differ from --identity");
+                exit(1);
+            }
+            if !validator_config.tower_storage.is_fenced() {
+                eprintln!("--standby-identity requires --tower-storage shared-file or etcd");
+                exit(1);
+            }
+            StandbyConfig {
+                primary_identity: Arc::new(primary_identity),
+                max_missed_slots: value_t_or_exit!(matches, "standby_max_missed_slots", Slot),
+            }
+        });
+    validator_config.check_tower_lease = matches.is_present("check_tower_lease");
+    if validator_config.check_tower_lease && !validator_config.tower_storage.is_fenced() {
+        eprintln!("--check-tower-lease requires --tower-storage shared-file or etcd");
+        exit(1);
+    }
 
     validator_config.ledger_column_options = LedgerColumnOptions {
         compression_type: match matches.value_of("rocksdb_ledger_compression") {